@@ -0,0 +1,37 @@
+use backend::location::board::{board, Board};
+
+// Checks a board file for problems, e.g. `cargo run --bin validate_board -- data/board.json`
+// Without a path the default board gets checked
+fn main() {
+	let board = match std::env::args().nth(1) {
+		Some(path) => {
+			let json = match std::fs::read_to_string(&path) {
+				Ok(json) => json,
+				Err(e) => {
+					println!("couldn't read {path}: {e}");
+					std::process::exit(2);
+				},
+			};
+			match Board::parse(&json) {
+				Ok(board) => board,
+				Err(e) => {
+					println!("couldn't load {path}: {e}");
+					std::process::exit(2);
+				},
+			}
+		},
+		None => board().clone(),
+	};
+
+	let problems = board.validate();
+	for problem in &problems {
+		println!("{problem}");
+	}
+
+	if !problems.is_empty() {
+		println!("{} problems found", problems.len());
+		std::process::exit(1);
+	}
+
+	println!("board is fine, {} locations", board.nodes.len());
+}
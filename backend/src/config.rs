@@ -0,0 +1,241 @@
+#[cfg(test)]
+mod test;
+
+use std::error::Error;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use crate::game_store::sweeper::{self, ExpirySettings};
+
+// Used when CONFIG_PATH isn't set, it's fine if it doesn't exist
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+// Everything that can be set about the server. It's read from a toml file (see config.example.toml),
+// environment variables override single values of it. Missing values get their default
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+	pub bind_address: IpAddr,
+	pub port: u16,
+	//None: one worker per cpu core
+	pub workers: Option<usize>,
+	pub log_level: LogLevel,
+	pub log_format: LogFormat,
+	//None: games are only kept in memory
+	pub persistence_path: Option<PathBuf>,
+	//None: the board that is built into the server
+	pub board_path: Option<PathBuf>,
+	//origins browsers may call the api from, "*" allows every origin. Empty: no cross origin requests
+	pub cors_origins: Vec<String>,
+	//seconds running requests get to finish after SIGTERM before the server stops anyway
+	pub shutdown_timeout: u64,
+	pub limits: Limits,
+	pub expiry: ExpirySettings,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Limits {
+	//games the server keeps at a time, new games fail with server_busy once there are this many. None: no limit
+	pub max_games: Option<usize>,
+	//bytes a request body may have
+	pub max_body_size: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+	Error,
+	Warn,
+	Info,
+	Debug,
+}
+
+// Text is meant for people reading the terminal, json for log collectors
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+	Text,
+	Json,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		return Config {
+			bind_address: IpAddr::from([0, 0, 0, 0]),
+			port: 4000,
+			workers: None,
+			log_level: LogLevel::Info,
+			log_format: LogFormat::Text,
+			persistence_path: None,
+			board_path: None,
+			cors_origins: Vec::new(),
+			shutdown_timeout: 30,
+			limits: Limits::default(),
+			expiry: ExpirySettings::default(),
+		};
+	}
+}
+
+impl Default for Limits {
+	fn default() -> Self {
+		return Limits {
+			max_games: None,
+			max_body_size: 64 * 1024,
+		};
+	}
+}
+
+impl TryFrom<&str> for LogLevel {
+	type Error = String;
+	fn try_from(value: &str) -> Result<LogLevel, String> {
+		return match value {
+			"error" => Ok(LogLevel::Error),
+			"warn" => Ok(LogLevel::Warn),
+			"info" => Ok(LogLevel::Info),
+			"debug" => Ok(LogLevel::Debug),
+			_ => Err(format!("{value} isn't a log level, use error, warn, info or debug")),
+		};
+	}
+}
+
+impl TryFrom<&str> for LogFormat {
+	type Error = String;
+	fn try_from(value: &str) -> Result<LogFormat, String> {
+		return match value {
+			"text" => Ok(LogFormat::Text),
+			"json" => Ok(LogFormat::Json),
+			_ => Err(format!("{value} isn't a log format, use text or json")),
+		};
+	}
+}
+
+impl Config {
+	// Reads the file at CONFIG_PATH (or config.toml if it exists), applies the environment variables and validates the result
+	pub fn load() -> Result<Config, Box<dyn Error>> {
+		let mut config = match std::env::var("CONFIG_PATH") {
+			Ok(path) => Config::from_file(Path::new(&path))?,
+			Err(_) if Path::new(DEFAULT_CONFIG_PATH).exists() => Config::from_file(Path::new(DEFAULT_CONFIG_PATH))?,
+			Err(_) => Config::default(),
+		};
+
+		config.apply_env(|name| std::env::var(name).ok())?;
+		config.validate()?;
+
+		return Ok(config);
+	}
+
+	pub fn from_file(path: &Path) -> Result<Config, Box<dyn Error>> {
+		let content = std::fs::read_to_string(path).map_err(|e| format!("couldn't read config file {}: {e}", path.display()))?;
+		return toml::from_str(&content).map_err(|e| format!("couldn't parse config file {}: {e}", path.display()).into());
+	}
+
+	// Overrides every value that has an environment variable set, env gets the value of a variable by name
+	pub fn apply_env(&mut self, env: impl Fn(&str) -> Option<String>) -> Result<(), Box<dyn Error>> {
+		if let Some(value) = env("BIND_ADDRESS") {
+			self.bind_address = parse("BIND_ADDRESS", &value)?;
+		}
+		if let Some(value) = env("PORT") {
+			self.port = parse("PORT", &value)?;
+		}
+		if let Some(value) = env("WORKERS") {
+			self.workers = Some(parse("WORKERS", &value)?);
+		}
+		if let Some(value) = env("LOG_LEVEL") {
+			self.log_level = LogLevel::try_from(value.as_str()).map_err(|e| format!("LOG_LEVEL: {e}"))?;
+		}
+		if let Some(value) = env("LOG_FORMAT") {
+			self.log_format = LogFormat::try_from(value.as_str()).map_err(|e| format!("LOG_FORMAT: {e}"))?;
+		}
+		if let Some(value) = env("PERSISTENCE_PATH") {
+			self.persistence_path = Some(PathBuf::from(value));
+		}
+		if let Some(value) = env("BOARD_PATH") {
+			self.board_path = Some(PathBuf::from(value));
+		}
+		if let Some(value) = env("CORS_ORIGINS") {
+			self.cors_origins = value.split(',').map(|x| x.trim().to_string()).filter(|x| !x.is_empty()).collect();
+		}
+		if let Some(value) = env("SHUTDOWN_TIMEOUT") {
+			self.shutdown_timeout = parse("SHUTDOWN_TIMEOUT", &value)?;
+		}
+		if let Some(value) = env("MAX_GAMES") {
+			self.limits.max_games = Some(parse("MAX_GAMES", &value)?);
+		}
+		if let Some(value) = env("MAX_BODY_SIZE") {
+			self.limits.max_body_size = parse("MAX_BODY_SIZE", &value)?;
+		}
+		if let Some(value) = env("LOBBY_TTL") {
+			self.expiry.lobby_ttl = parse("LOBBY_TTL", &value)?;
+		}
+		if let Some(value) = env("IN_PROGRESS_TTL") {
+			self.expiry.in_progress_ttl = parse("IN_PROGRESS_TTL", &value)?;
+		}
+		if let Some(value) = env("FINISHED_TTL") {
+			self.expiry.finished_ttl = parse("FINISHED_TTL", &value)?;
+		}
+		if let Some(value) = env("ARCHIVE_PATH") {
+			self.expiry.archive_path = Some(PathBuf::from(value));
+		}
+
+		return Ok(());
+	}
+
+	pub fn validate(&self) -> Result<(), Box<dyn Error>> {
+		if self.port == 0 {
+			return Err("port has to be between 1 and 65535".into());
+		}
+
+		if self.workers == Some(0) {
+			return Err("workers has to be at least 1".into());
+		}
+
+		if self.limits.max_games == Some(0) {
+			return Err("max_games has to be at least 1".into());
+		}
+
+		//the largest valid request is a move with a few event cards
+		if self.limits.max_body_size < 1024 {
+			return Err("max_body_size has to be at least 1024 bytes".into());
+		}
+
+		if self.expiry.lobby_ttl == 0 || self.expiry.in_progress_ttl == 0 || self.expiry.finished_ttl == 0 {
+			return Err("lobby_ttl, in_progress_ttl and finished_ttl have to be at least 1 second".into());
+		}
+
+		if [self.expiry.lobby_ttl, self.expiry.in_progress_ttl, self.expiry.finished_ttl].iter().any(|x| *x > sweeper::MAX_TTL) {
+			return Err(format!("lobby_ttl, in_progress_ttl and finished_ttl can be at most {} seconds", sweeper::MAX_TTL).into());
+		}
+
+		if self.cors_origins.contains(&"*".to_string()) && self.cors_origins.len() > 1 {
+			return Err("cors_origins can't contain other origins next to \"*\"".into());
+		}
+
+		if let Some(origin) = self.cors_origins.iter().find(|x| *x != "*" && !is_origin(x)) {
+			return Err(format!("cors origin {origin} has to be a scheme and host like https://example.com").into());
+		}
+
+		if let Some(board_path) = &self.board_path {
+			if !board_path.is_file() {
+				return Err(format!("board_path {} doesn't exist", board_path.display()).into());
+			}
+		}
+
+		return Ok(());
+	}
+}
+
+// Whether the browser could send this as Origin header: http or https, a host, maybe a port and nothing else.
+// cors() can't handle anything else
+fn is_origin(origin: &str) -> bool {
+	let uri: actix_web::http::Uri = match origin.parse() {
+		Ok(uri) => uri,
+		Err(_) => return false,
+	};
+
+	//the parsed path is / even without one, so a trailing slash is only visible in the origin itself
+	return matches!(uri.scheme_str(), Some("http" | "https")) && uri.host().is_some_and(|x| !x.is_empty()) && uri.path() == "/" && uri.query().is_none() && !origin.ends_with('/');
+}
+
+fn parse<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> where T::Err: std::fmt::Display {
+	return value.trim().parse().map_err(|e| format!("{name} has an invalid value {value}: {e}"));
+}
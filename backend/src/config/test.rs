@@ -0,0 +1,183 @@
+use super::*;
+use std::collections::BTreeMap;
+
+fn temp_file(content: &str) -> PathBuf {
+	let path = std::env::temp_dir().join(format!("timetabletag-config-{}.toml", uuid::Uuid::new_v4()));
+	std::fs::write(&path, content).unwrap();
+	return path;
+}
+
+fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+	let vars: BTreeMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+	return move |name: &str| vars.get(name).cloned();
+}
+
+fn validation_error(config: Config) -> String {
+	return config.validate().unwrap_err().to_string();
+}
+
+mod file {
+	use super::*;
+
+	#[test]
+	fn example_config_is_the_default() {
+		let config: Config = toml::from_str(include_str!("../../config.example.toml")).unwrap();
+
+		assert_eq!(config, Config::default());
+		assert!(config.validate().is_ok());
+	}
+
+	#[test]
+	fn values_are_read() {
+		let path = temp_file("bind_address = \"127.0.0.1\"\nport = 8080\nworkers = 2\nlog_level = \"debug\"\npersistence_path = \"/data\"\ncors_origins = [\"https://example.com\"]\n[limits]\nmax_games = 100\n[expiry]\nlobby_ttl = 60\narchive_path = \"/archive\"\n");
+
+		let config = Config::from_file(&path).unwrap();
+
+		assert_eq!(config.bind_address, IpAddr::from([127, 0, 0, 1]));
+		assert_eq!(config.port, 8080);
+		assert_eq!(config.workers, Some(2));
+		assert_eq!(config.log_level, LogLevel::Debug);
+		assert_eq!(config.persistence_path, Some(PathBuf::from("/data")));
+		assert_eq!(config.cors_origins, vec!["https://example.com".to_string()]);
+		assert_eq!(config.limits.max_games, Some(100));
+		assert_eq!(config.expiry.lobby_ttl, 60);
+		assert_eq!(config.expiry.archive_path, Some(PathBuf::from("/archive")));
+
+		let _ = std::fs::remove_file(path);
+	}
+
+	#[test]
+	fn missing_values_keep_their_default() {
+		let path = temp_file("port = 8080\n[expiry]\nfinished_ttl = 5\n");
+
+		let config = Config::from_file(&path).unwrap();
+
+		assert_eq!(config, Config {
+			port: 8080,
+			expiry: ExpirySettings { finished_ttl: 5, ..Default::default() },
+			..Default::default()
+		});
+
+		let _ = std::fs::remove_file(path);
+	}
+
+	#[test]
+	fn unknown_keys_are_rejected() {
+		let path = temp_file("prot = 8080\n");
+
+		let res = Config::from_file(&path);
+
+		assert!(res.is_err());
+		assert!(res.err().unwrap().to_string().contains("prot"));
+
+		let _ = std::fs::remove_file(path);
+	}
+
+	#[test]
+	fn missing_file_is_an_error() {
+		let res = Config::from_file(Path::new("/does/not/exist.toml"));
+
+		assert!(res.is_err());
+		assert!(res.err().unwrap().to_string().contains("/does/not/exist.toml"));
+	}
+}
+
+mod env {
+	use super::*;
+
+	#[test]
+	fn overrides_file_values() {
+		let mut config = Config { port: 8080, workers: Some(2), ..Default::default() };
+
+		config.apply_env(env(&[("PORT", "9000"), ("BIND_ADDRESS", "::1"), ("LOG_LEVEL", "warn"), ("LOG_FORMAT", "json"), ("MAX_GAMES", "10"), ("FINISHED_TTL", "30"), ("SHUTDOWN_TIMEOUT", "5"), ("PERSISTENCE_PATH", "/data")])).unwrap();
+
+		assert_eq!(config.port, 9000);
+		assert_eq!(config.workers, Some(2));
+		assert_eq!(config.bind_address, "::1".parse::<IpAddr>().unwrap());
+		assert_eq!(config.log_level, LogLevel::Warn);
+		assert_eq!(config.log_format, LogFormat::Json);
+		assert_eq!(config.limits.max_games, Some(10));
+		assert_eq!(config.expiry.finished_ttl, 30);
+		assert_eq!(config.shutdown_timeout, 5);
+		assert_eq!(config.persistence_path, Some(PathBuf::from("/data")));
+	}
+
+	#[test]
+	fn cors_origins_are_comma_separated() {
+		let mut config = Config::default();
+
+		config.apply_env(env(&[("CORS_ORIGINS", "https://a.example, https://b.example,")])).unwrap();
+
+		assert_eq!(config.cors_origins, vec!["https://a.example".to_string(), "https://b.example".to_string()]);
+	}
+
+	#[test]
+	fn invalid_values_name_the_variable() {
+		for (name, value) in [("PORT", "forty"), ("PORT", "70000"), ("WORKERS", "-1"), ("BIND_ADDRESS", "localhost"), ("LOG_LEVEL", "trace"), ("LOG_FORMAT", "yaml"), ("LOBBY_TTL", "1h"), ("SHUTDOWN_TIMEOUT", "-5")] {
+			let res = Config::default().apply_env(env(&[(name, value)]));
+
+			assert!(res.is_err(), "{name}={value} was accepted");
+			assert!(res.err().unwrap().to_string().contains(name));
+		}
+	}
+}
+
+mod validate {
+	use super::*;
+
+	#[test]
+	fn default_is_valid() {
+		assert!(Config::default().validate().is_ok());
+	}
+
+	#[test]
+	fn port_zero_is_invalid() {
+		assert!(validation_error(Config { port: 0, ..Default::default() }).contains("port"));
+	}
+
+	#[test]
+	fn zero_workers_are_invalid() {
+		assert!(validation_error(Config { workers: Some(0), ..Default::default() }).contains("workers"));
+	}
+
+	#[test]
+	fn limits_have_a_minimum() {
+		assert!(validation_error(Config { limits: Limits { max_games: Some(0), ..Default::default() }, ..Default::default() }).contains("max_games"));
+		assert!(validation_error(Config { limits: Limits { max_body_size: 10, ..Default::default() }, ..Default::default() }).contains("max_body_size"));
+	}
+
+	#[test]
+	fn ttls_have_to_be_positive() {
+		assert!(validation_error(Config { expiry: ExpirySettings { in_progress_ttl: 0, ..Default::default() }, ..Default::default() }).contains("in_progress_ttl"));
+	}
+
+	#[test]
+	fn ttls_have_a_maximum() {
+		assert!(validation_error(Config { expiry: ExpirySettings { lobby_ttl: u64::MAX, ..Default::default() }, ..Default::default() }).contains("lobby_ttl"));
+	}
+
+	#[test]
+	fn cors_origins_need_a_scheme() {
+		assert!(validation_error(Config { cors_origins: vec!["example.com".to_string()], ..Default::default() }).contains("example.com"));
+		assert!(Config { cors_origins: vec!["https://example.com".to_string(), "http://localhost:3000".to_string()], ..Default::default() }.validate().is_ok());
+	}
+
+	#[test]
+	fn cors_origins_have_to_be_valid_origins() {
+		for origin in ["https://bad host", "https://", "ftp://example.com", "https://example.com/", "https://example.com/path", "https://example.com?x=1"] {
+			assert!(validation_error(Config { cors_origins: vec![origin.to_string()], ..Default::default() }).contains(origin));
+		}
+	}
+
+	#[test]
+	fn wildcard_origin_has_to_be_alone() {
+		assert!(Config { cors_origins: vec!["*".to_string()], ..Default::default() }.validate().is_ok());
+		assert!(validation_error(Config { cors_origins: vec!["*".to_string(), "https://example.com".to_string()], ..Default::default() }).contains("*"));
+	}
+
+	#[test]
+	fn board_path_has_to_exist() {
+		assert!(validation_error(Config { board_path: Some(PathBuf::from("/does/not/exist.json")), ..Default::default() }).contains("/does/not/exist.json"));
+		assert!(Config { board_path: Some(PathBuf::from("data/board.json")), ..Default::default() }.validate().is_ok());
+	}
+}
@@ -4,7 +4,7 @@ mod test;
 use std::fmt::Display;
 use rand::prelude::*;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
 pub enum EventCard {
 	GiveMeYourCards,
 	HuntedByMenForSport,
@@ -56,8 +56,8 @@ impl Display for EventCard {
 }
 
 impl std::convert::TryFrom<String> for EventCard {
-	type Error = String;
-	fn try_from(value: String) -> Result<EventCard, String> {
+	type Error = crate::CustomError;
+	fn try_from(value: String) -> Result<EventCard, crate::CustomError> {
 		match value.as_str() {
 			"give_me_your_cards" => Ok(EventCard::GiveMeYourCards),
 			"hunted_by_men_for_sport" => Ok(EventCard::HuntedByMenForSport),
@@ -79,13 +79,12 @@ impl std::convert::TryFrom<String> for EventCard {
 			"bonjour_to_everyone" => Ok(EventCard::BonjourToEveryone),
 			"no_talk" => Ok(EventCard::NoTalk),
 			"slovenia_as_a_treat" => Ok(EventCard::SloveniaAsATreat),
-			_ => Err(format!("{value} not a valid EventCard ID")),
+			_ => Err(crate::CustomError::InvalidEventCard(value)),
 		}
 	}
 }
 
-pub fn generate_event_card_stack() -> Vec<EventCard> {
-	let mut rng = thread_rng();
+pub fn generate_event_card_stack(rng: &mut impl Rng) -> Vec<EventCard> {
 	let mut output: Vec<EventCard> = vec![
 		EventCard::GiveMeYourCards,
 		EventCard::HuntedByMenForSport,
@@ -109,7 +108,7 @@ pub fn generate_event_card_stack() -> Vec<EventCard> {
 		EventCard::SloveniaAsATreat,
 	];
 
-	output.shuffle(&mut rng);
+	output.shuffle(rng);
 
 	return output;
 }
\ No newline at end of file
@@ -11,7 +11,7 @@ mod generate_event_card_stack {
 		//check the first
 		let mut output: BTreeMap<EventCard, usize> = BTreeMap::new();
 		for _ in 0..n {
-			output.entry(generate_event_card_stack().first().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
+			output.entry(generate_event_card_stack(&mut thread_rng()).first().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
 		}
 
 		assert!(*output.get(&EventCard::BingBong).unwrap() as f64 / n as f64 > 0.03);
@@ -20,7 +20,7 @@ mod generate_event_card_stack {
 		//and again the last
 		let mut output: BTreeMap<EventCard, usize> = BTreeMap::new();
 		for _ in 0..n {
-			output.entry(generate_event_card_stack().pop().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
+			output.entry(generate_event_card_stack(&mut thread_rng()).pop().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
 		}
 
 		assert!(*output.get(&EventCard::BingBong).unwrap() as f64 / n as f64 > 0.03);
@@ -31,7 +31,7 @@ mod generate_event_card_stack {
 
 	#[test]
 	fn card_stack_contains_right_amount_of_each() {
-		let res = generate_event_card_stack();
+		let res = generate_event_card_stack(&mut thread_rng());
 		
 		assert_eq!(res.len(), 20);
 		assert_eq!(res.iter().filter(|x| **x == EventCard::GiveMeYourCards).count(), 1);
@@ -55,4 +55,12 @@ mod generate_event_card_stack {
 		assert_eq!(res.iter().filter(|x| **x == EventCard::NoTalk).count(), 1);
 		assert_eq!(res.iter().filter(|x| **x == EventCard::SloveniaAsATreat).count(), 1);
 	}
+
+	#[test]
+	fn same_seed_results_in_same_order() {
+		let res_1 = generate_event_card_stack(&mut rand_chacha::ChaCha8Rng::seed_from_u64(1234));
+		let res_2 = generate_event_card_stack(&mut rand_chacha::ChaCha8Rng::seed_from_u64(1234));
+
+		assert_eq!(res_1, res_2);
+	}
 }
\ No newline at end of file
@@ -0,0 +1,196 @@
+use super::*;
+use in_progress_game::{InProgressGame, Move};
+use legal_moves::LegalMove;
+
+// Upper limit of moves bots make in a row, so a bot that can't do anything doesn't hang the server
+const MAX_BOT_MOVES: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BotDifficulty {
+	// Makes random moves and only takes wins that are right in front of it
+	Easy,
+	// Runner heads for the destination while keeping away from chasers,
+	// chasers buy powerups to find the runner and close in on it
+	Hard,
+}
+
+impl Game {
+	// Lets bots make their moves until it's the turn of a human again or the game is over
+	pub fn play_bot_turns(&mut self) {
+		for _ in 0..MAX_BOT_MOVES {
+			let move_made = match self {
+				Game::InProgress(game) => match game.players.iter().find(|x| x.id == game.current_turn).and_then(|x| x.bot) {
+					Some(difficulty) => game.next_bot_move(difficulty),
+					None => return,
+				},
+				_ => return,
+			};
+
+			if let Err(e) = self.make_move(move_made) {
+				tracing::warn!(game_id = %self.id(), error = %e, "bot couldn't make its move");
+				return;
+			}
+		}
+	}
+}
+
+impl InProgressGame {
+	// The next move of the bot whose turn it is. Every decision is derived from the seed and the turn,
+	// so bots don't touch the rng of the game and seeded games stay replayable.
+	pub fn next_bot_move(&self, difficulty: BotDifficulty) -> Move {
+		let player_id = self.current_turn;
+		let mut rng = GameRng::seed_from_u64(self.seed.wrapping_add(self.turn as u64));
+
+		if difficulty == BotDifficulty::Hard && player_id != self.runner && self.in_progress_move.is_none() {
+			if let Some(powerup) = self.powerup_worth_buying() {
+				return Move {
+					player_id,
+					buy_powerup: Some(powerup.to_string()),
+					..Default::default()
+				};
+			}
+		}
+
+		let mut legal_moves = self.legal_moves(player_id);
+		if legal_moves.is_empty() {
+			let player = self.players.iter().find(|x| x.id == player_id).unwrap();
+
+			return Move {
+				player_id,
+				throw_timetable_cards_away: if in_progress_game::is_move_possible(player) {Vec::new()} else {player.timetable_cards.iter().take(2).map(|x| x.to_string()).collect()},
+				finish_move: true,
+				..Default::default()
+			};
+		}
+		legal_moves.shuffle(&mut rng);
+
+		let chosen_move = if player_id == self.runner {
+			self.choose_runner_move(difficulty, legal_moves)
+		} else {
+			self.choose_chaser_move(difficulty, legal_moves)
+		};
+
+		return Move {
+			player_id,
+			next_location: Some(chosen_move.next_location.to_string()),
+			use_timetable_card: chosen_move.timetable_card.map(|x| x.to_string()),
+			finish_move: true,
+			..Default::default()
+		};
+	}
+
+	fn choose_runner_move(&self, difficulty: BotDifficulty, legal_moves: Vec<LegalMove>) -> LegalMove {
+		if self.coins_runner >= self.settings.coins_needed_at_destination {
+			if let Some(winning_move) = legal_moves.iter().find(|x| x.next_location == self.destination) {
+				return winning_move.clone();
+			}
+		}
+
+		if difficulty == BotDifficulty::Easy {
+			return legal_moves.into_iter().next().unwrap();
+		}
+
+		let to_destination = distances_from(self.destination);
+		//the runner only knows where chasers are that aren't in stealth mode
+		let chaser_distances: Vec<BTreeMap<Location, usize>> = self.players.iter()
+			.filter(|x| x.id != self.runner && !x.stealth_mode_active)
+			.map(|x| distances_from(x.current_location))
+			.collect();
+
+		return legal_moves.into_iter().min_by_key(|x| {
+			let closest_chaser = chaser_distances.iter().map(|distances| distance(distances, x.next_location)).min().unwrap_or(usize::MAX);
+			let in_danger = closest_chaser <= 1;
+			let coins_to_collect = self.coins_runner < self.settings.coins_needed_at_destination && x.next_location.is_coin_field();
+
+			return (in_danger, distance(&to_destination, x.next_location).saturating_sub(coins_to_collect as usize));
+		}).unwrap();
+	}
+
+	fn choose_chaser_move(&self, difficulty: BotDifficulty, legal_moves: Vec<LegalMove>) -> LegalMove {
+		let targets = self.known_runner_locations();
+
+		if let [runner_location] = targets.as_slice() {
+			if let Some(catching_move) = legal_moves.iter().find(|x| x.next_location == *runner_location) {
+				return catching_move.clone();
+			}
+		}
+
+		if difficulty == BotDifficulty::Easy || targets.is_empty() {
+			return legal_moves.into_iter().next().unwrap();
+		}
+
+		let target_distances: Vec<BTreeMap<Location, usize>> = targets.into_iter().map(distances_from).collect();
+
+		return legal_moves.into_iter().min_by_key(|x| {
+			return target_distances.iter().map(|distances| distance(distances, x.next_location)).min().unwrap_or(usize::MAX);
+		}).unwrap();
+	}
+
+	// Where the chasers think the runner is, based on the powerups bought so far
+	fn known_runner_locations(&self) -> Vec<Location> {
+		let mut runner_country = None;
+		let mut runner_destination = None;
+
+		for entry in self.history.iter().rev() {
+			let power_up_status = &entry.move_result.power_up_status;
+
+			if let Some(runner_location) = power_up_status.runner_location {
+				return vec![runner_location];
+			}
+			if runner_country.is_none() {
+				runner_country = power_up_status.runner_country;
+			}
+			if runner_destination.is_none() {
+				runner_destination = power_up_status.runner_destination;
+			}
+		}
+
+		if let Some(runner_country) = runner_country {
+			return Location::get_iter().filter(|x| Country::try_from(*x).is_ok_and(|x| x == runner_country)).collect();
+		}
+
+		return runner_destination.into_iter().collect();
+	}
+
+	// Chasers only spend coins when they haven't learned anything about the runner since its last move
+	fn powerup_worth_buying(&self) -> Option<Powerup> {
+		let learned_since_runners_last_move = self.history.iter().rev()
+			.take_while(|x| x.player_id != self.runner)
+			.any(|x| x.move_result.power_up_status.runner_location.is_some());
+
+		if learned_since_runners_last_move {
+			return None;
+		}
+
+		let chaser_count = self.players.len() - 1;
+		if self.coins_chasers >= Powerup::LearnRunnerLocation.get_price(chaser_count, &self.settings.powerup_prices) {
+			return Some(Powerup::LearnRunnerLocation);
+		}
+
+		return None;
+	}
+}
+
+// Number of hops from the given location to every other location, using any kind of connection
+fn distances_from(start: Location) -> BTreeMap<Location, usize> {
+	let mut distances: BTreeMap<Location, usize> = BTreeMap::new();
+	let mut queue = std::collections::VecDeque::from([start]);
+	distances.insert(start, 0);
+
+	while let Some(location) = queue.pop_front() {
+		let next_distance = distances[&location] + 1;
+		for connection in location.get_joker_connections() {
+			if let std::collections::btree_map::Entry::Vacant(entry) = distances.entry(connection) {
+				entry.insert(next_distance);
+				queue.push_back(connection);
+			}
+		}
+	}
+
+	return distances;
+}
+
+fn distance(distances: &BTreeMap<Location, usize>, location: Location) -> usize {
+	return distances.get(&location).copied().unwrap_or(usize::MAX);
+}
@@ -0,0 +1,137 @@
+use super::*;
+
+pub type ChallengeId = Uuid;
+
+// Flavor event cards are social challenges: the player who drew one has to do something in real life
+// and the other players decide whether it counts
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Challenge {
+	pub id: ChallengeId,
+	pub player_id: PlayerId,
+	pub event_card: EventCard,
+	pub turn: usize,
+	pub confirmed_by: Vec<PlayerId>,
+	pub rejected_by: Vec<PlayerId>,
+	//None while the other players are still voting
+	pub outcome: Option<ChallengeOutcome>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChallengeOutcome {
+	Completed,
+	Failed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Consequence {
+	Coins(usize),
+	LoseCoins(usize),
+	SkipTurn,
+}
+
+pub fn is_challenge(event_card: &EventCard) -> bool {
+	return matches!(event_card, EventCard::HydrateOrDiedrate | EventCard::SnackZone | EventCard::BonjourToEveryone | EventCard::NoTalk | EventCard::BingBong);
+}
+
+// What the team of the player gets for completing the challenge and what happens when the others reject it
+fn consequences(event_card: &EventCard) -> (Consequence, Consequence) {
+	return match event_card {
+		//drink something
+		EventCard::HydrateOrDiedrate => (Consequence::Coins(2), Consequence::LoseCoins(1)),
+		//eat a snack
+		EventCard::SnackZone => (Consequence::Coins(2), Consequence::LoseCoins(1)),
+		//greet everyone in french
+		EventCard::BonjourToEveryone => (Consequence::Coins(1), Consequence::LoseCoins(1)),
+		//don't say a word until your next turn
+		EventCard::NoTalk => (Consequence::Coins(3), Consequence::SkipTurn),
+		//bing bong
+		EventCard::BingBong => (Consequence::Coins(1), Consequence::SkipTurn),
+		_ => (Consequence::Coins(0), Consequence::Coins(0)),
+	};
+}
+
+impl InProgressGame {
+	pub(super) fn start_challenge(&mut self, player_id: PlayerId, event_card: EventCard) -> ChallengeId {
+		let id = ChallengeId::new_v4();
+		self.challenges.push(Challenge {
+			id,
+			player_id,
+			event_card,
+			turn: self.turn,
+			confirmed_by: Vec::new(),
+			rejected_by: Vec::new(),
+			outcome: None,
+		});
+
+		//nobody can watch bots do anything, so their challenges count right away
+		if self.players.iter().any(|x| x.id == player_id && x.bot.is_some()) {
+			self.resolve_challenge(id, ChallengeOutcome::Completed);
+		} else {
+			self.resolve_challenge_when_decided(id);
+		}
+
+		return id;
+	}
+
+	// Every other human player gets a vote, voting again replaces the earlier vote
+	pub fn vote_on_challenge(&mut self, player_id: PlayerId, challenge_id: ChallengeId, confirm: bool) -> Result<Option<ChallengeOutcome>, Box<dyn Error>> {
+		let challenge = match self.challenges.iter_mut().find(|x| x.id == challenge_id) {
+			Some(challenge) => challenge,
+			None => return Err(Box::new(crate::CustomError::ChallengeNotFound)),
+		};
+
+		if challenge.outcome.is_some() {
+			return Err(Box::new(crate::CustomError::InvalidGameState));
+		}
+
+		if challenge.player_id == player_id || !self.players.iter().any(|x| x.id == player_id && x.bot.is_none()) {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		challenge.confirmed_by.retain(|x| *x != player_id);
+		challenge.rejected_by.retain(|x| *x != player_id);
+		if confirm {
+			challenge.confirmed_by.push(player_id);
+		} else {
+			challenge.rejected_by.push(player_id);
+		}
+
+		return Ok(self.resolve_challenge_when_decided(challenge_id));
+	}
+
+	// More than half of the voters have to confirm, at least half rejecting is enough to fail.
+	// Without anyone to vote the challenge counts right away
+	fn resolve_challenge_when_decided(&mut self, challenge_id: ChallengeId) -> Option<ChallengeOutcome> {
+		let challenge = self.challenges.iter().find(|x| x.id == challenge_id).unwrap();
+		let voters = self.players.iter().filter(|x| x.id != challenge.player_id && x.bot.is_none()).count();
+
+		let outcome = if voters == 0 || challenge.confirmed_by.len() * 2 > voters {
+			ChallengeOutcome::Completed
+		} else if challenge.rejected_by.len() * 2 >= voters {
+			ChallengeOutcome::Failed
+		} else {
+			return None;
+		};
+
+		self.resolve_challenge(challenge_id, outcome);
+
+		return Some(outcome);
+	}
+
+	fn resolve_challenge(&mut self, challenge_id: ChallengeId, outcome: ChallengeOutcome) {
+		let challenge = self.challenges.iter_mut().find(|x| x.id == challenge_id).unwrap();
+		challenge.outcome = Some(outcome);
+
+		let player_id = challenge.player_id;
+		let (reward, penalty) = consequences(&challenge.event_card);
+		let consequence = if outcome == ChallengeOutcome::Completed {reward} else {penalty};
+		let coins = if player_id == self.runner {&mut self.coins_runner} else {&mut self.coins_chasers};
+
+		match consequence {
+			Consequence::Coins(amount) => *coins += amount,
+			Consequence::LoseCoins(amount) => *coins = coins.saturating_sub(amount),
+			Consequence::SkipTurn => self.players.iter_mut().find(|x| x.id == player_id).unwrap().skip_turns += 1,
+		}
+	}
+}
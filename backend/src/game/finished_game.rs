@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct FinishedGame {
 	pub id: GameId,
 	pub host: PlayerId,
@@ -12,10 +12,33 @@ pub struct FinishedGame {
 	pub winning_team: Team,
 	pub win_condition: WinCondition,
 	pub runner_path: Vec<Location>,
+	//only revealed once the game is over, knowing it would give away the order of all card stacks
+	pub seed: u64,
+	pub history: Vec<HistoryEntry>,
+	#[serde(default)]
+	pub dice_rolls: Vec<DiceRoll>,
+	#[serde(default)]
+	pub spectators: Spectators,
+	#[serde(default)]
+	pub settings: GameSettings,
+	//unix time in milliseconds of the last change, see game_store/sweeper.rs
+	#[serde(default)]
+	pub last_activity: u64,
+	//set once a player asked for a rematch, see rematch.rs
+	#[serde(default)]
+	pub rematch: Option<Rematch>,
+	//the series with the result of this round, see series.rs
+	#[serde(default)]
+	pub series: Option<Series>,
 }
 
 impl FinishedGame {
 	pub fn from_in_progress_game(in_progress_game: &InProgressGame, winning_team: Team, win_condition: WinCondition) -> FinishedGame {
+		let series = in_progress_game.series.as_deref().cloned().map(|mut series| {
+			series.record_round(in_progress_game, &win_condition);
+			return series;
+		});
+
 		return FinishedGame {
 			id: in_progress_game.id,
 			host: in_progress_game.host,
@@ -27,6 +50,14 @@ impl FinishedGame {
 			winning_team,
 			win_condition,
 			runner_path: in_progress_game.runner_path.clone(), 
+			seed: in_progress_game.seed,
+			history: in_progress_game.history.clone(),
+			dice_rolls: in_progress_game.dice_rolls.clone(),
+			spectators: in_progress_game.spectators.clone(),
+			settings: in_progress_game.settings.clone(),
+			last_activity: history::now(),
+			rematch: None,
+			series,
 		};
 	}
 }
\ No newline at end of file
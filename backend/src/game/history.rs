@@ -0,0 +1,74 @@
+use super::*;
+use in_progress_game::{Move, MoveResult};
+
+// One accepted call of make_move
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HistoryEntry {
+	pub turn: usize,
+	pub player_id: PlayerId,
+	pub timestamp: u64,
+	pub move_made: Move,
+	pub move_result: MoveResult,
+	pub random_outcomes: Vec<RandomOutcome>,
+	pub location_hidden: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum RandomOutcome {
+	CoinFieldRoll(usize),
+	PizzazzRunnerRoll(usize),
+	PizzazzChaserRolls(Vec<usize>),
+	EventCardTakenFrom(PlayerId, EventCard),
+}
+
+// Every die rolled in the game. Rolls are public, so opponents can see how many coins were won and why
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DiceRoll {
+	pub turn: usize,
+	pub player_id: PlayerId,
+	pub reason: RollReason,
+	pub roll: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RollReason {
+	CoinField,
+	Pizzazz,
+}
+
+// Rolls a six sided die for the player and records the roll
+pub fn roll_die(rng: &mut GameRng, turn: usize, player_id: PlayerId, reason: RollReason, dice_rolls: &mut Vec<DiceRoll>) -> usize {
+	let roll: usize = rng.gen_range(1..=6);
+	dice_rolls.push(DiceRoll { turn, player_id, reason, roll: roll as u8 });
+
+	return roll;
+}
+
+impl HistoryEntry {
+	// Removes everything the viewer isn't allowed to know yet while the game is still running
+	pub fn visible_to(&self, viewer: PlayerId, runner: PlayerId) -> HistoryEntry {
+		let mut entry = self.clone();
+
+		if self.player_id == viewer {
+			return entry;
+		}
+
+		if self.player_id == runner {
+			entry.move_made.next_location = None;
+			entry.move_made.next_location_parsed = None;
+			entry.move_made.throw_timetable_cards_away = Vec::new();
+			entry.move_result.timetable_cards_received = Vec::new();
+			entry.move_result.event_card_received = None;
+		} else if viewer == runner && self.location_hidden {
+			entry.move_made.next_location = None;
+			entry.move_made.next_location_parsed = None;
+		}
+
+		return entry;
+	}
+}
+
+pub fn now() -> u64 {
+	return std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|x| x.as_millis() as u64).unwrap_or_default();
+}
@@ -2,7 +2,7 @@ use super::*;
 
 use rand::prelude::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct InProgressGame {
 	pub id: GameId,
 	pub host: PlayerId,
@@ -13,7 +13,10 @@ pub struct InProgressGame {
 	pub coins_runner: usize,
 	pub coins_chasers: usize,
 	pub last_used_timetable_card: Option<TimetableCard>,
+	//the last die rolled in the game, dice_rolls has every roll
 	pub dice_result: Option<u8>,
+	#[serde(default)]
+	pub dice_rolls: Vec<DiceRoll>,
 	pub event_card_bought: bool,
 	pub runner_path: Vec<Location>,
 	pub in_progress_move: Option<InProgressMove>,
@@ -21,15 +24,67 @@ pub struct InProgressGame {
 	pub event_card_stack: Vec<EventCard>,
 	pub power_up_status: PowerupStatus,
 	pub get_extra_turns: u8,
+	pub seed: u64,
+	pub rng: GameRng,
+	pub turn: usize,
+	//unix time in milliseconds the current turn started at, see turn_timer.rs
+	#[serde(default)]
+	pub turn_started_at: u64,
+	pub history: Vec<HistoryEntry>,
+	#[serde(default)]
+	pub spectators: Spectators,
+	#[serde(default)]
+	pub settings: GameSettings,
+	#[serde(default)]
+	pub challenges: Vec<Challenge>,
+	//unix time in milliseconds of the last change, see game_store/sweeper.rs
+	#[serde(default)]
+	pub last_activity: u64,
+	//only set when settings.series is on, see series.rs. Boxed, most games aren't a series
+	#[serde(default)]
+	pub series: Option<Box<Series>>,
 }
 
 impl InProgressGame {
-	pub fn make_move(&mut self, mut move_made: Move) -> Result<MoveResult, Box<dyn Error>> {
+	pub fn make_move(&mut self, move_made: Move) -> Result<MoveResult, Box<dyn Error>> {
+		let turn = self.turn;
+		let player_id = move_made.player_id;
+		let recorded_move = move_made.clone();
+		let mut random_outcomes: Vec<RandomOutcome> = Vec::new();
+
+		let mut move_result = self.apply_move(move_made, &mut random_outcomes)?;
+
+		let mut recorded_result = move_result.clone();
+		recorded_result.finished_game = None;
+
+		self.history.push(HistoryEntry {
+			turn,
+			player_id,
+			timestamp: history::now(),
+			move_made: recorded_move,
+			move_result: recorded_result,
+			random_outcomes,
+			location_hidden: self.players.iter().find(|x| x.id == player_id).is_some_and(|x| x.stealth_mode_active),
+		});
+
+		if let Some(finished_game) = move_result.finished_game.as_mut() {
+			finished_game.history = self.history.clone();
+		}
+
+		return Ok(move_result);
+	}
+
+	// The history of the game as far as the given player is allowed to know it
+	pub fn history_for(&self, player_id: PlayerId) -> Vec<HistoryEntry> {
+		return self.history.iter().map(|x| x.visible_to(player_id, self.runner)).collect();
+	}
+
+	pub(super) fn apply_move(&mut self, mut move_made: Move, random_outcomes: &mut Vec<RandomOutcome>) -> Result<MoveResult, Box<dyn Error>> {
 		if move_made.player_id != self.current_turn {
 			return Err(Box::new(crate::CustomError::NotYourTurn));
 		}
 
-		let mut rng = thread_rng();
+		let mut rng = self.rng.clone();
 		let mut move_result = MoveResult::default();
 
 		let mut player: Player = self.players.clone().into_iter().find(|x| x.id == move_made.player_id).unwrap();
@@ -122,7 +177,7 @@ impl InProgressGame {
 			player.next_move_must_go_north_active = false;
 		}
 
-		if player.zug_faellt_aus_active && move_made.next_location_parsed.is_some() && Country::from(player.current_location) == Country::Germany {
+		if player.zug_faellt_aus_active && move_made.next_location_parsed.is_some() && Country::try_from(player.current_location)? == Country::Germany {
 			in_progress_move.new_location_already_sent = true;
 			in_progress_move.use_timetable_card_already_sent = true;
 
@@ -148,7 +203,7 @@ impl InProgressGame {
 
 			if player.leave_country_immediately_active {
 				if player_can_leave_country(&player) {
-					if Country::from(player.current_location) != Country::from(move_made.next_location_parsed.unwrap()) {
+					if Country::try_from(player.current_location)? != Country::try_from(move_made.next_location_parsed.unwrap())? {
 						player.leave_country_immediately_active = false;
 					} else {
 						return Err(Box::new(crate::CustomError::YouMustLeaveTheCountryImmediately));
@@ -211,7 +266,7 @@ impl InProgressGame {
 			if self.runner == player.id {
 				runner_path.push(move_made.next_location_parsed.unwrap());
 
-				if move_made.next_location_parsed.unwrap() == self.destination && coins_runner >= 10 {
+				if move_made.next_location_parsed.unwrap() == self.destination && coins_runner >= self.settings.coins_needed_at_destination {
 					move_result.finished_game = Some(FinishedGame::from_in_progress_game(&self, Team::Runner, WinCondition::GotToDestination));
 					return Ok(move_result);
 				}
@@ -225,10 +280,10 @@ impl InProgressGame {
 			}
 
 			if move_made.next_location_parsed.unwrap().is_coin_field() {
-				let mut rng = thread_rng();
-				let coins = rng.gen_range(1..=6);
+				let coins = history::roll_die(&mut rng, self.turn, player.id, RollReason::CoinField, &mut move_result.dice_rolls);
 
 				move_result.coins_received = Some(coins);
+				random_outcomes.push(RandomOutcome::CoinFieldRoll(coins));
 
 				if self.current_turn == self.runner {
 					coins_runner += coins;
@@ -253,15 +308,16 @@ impl InProgressGame {
 		if move_made.buy_powerup.is_some() && player.id != self.runner {
 			let powerup: Powerup = move_made.buy_powerup.unwrap().as_str().try_into()?;
 
-			if coins_chasers < powerup.get_price(players.len() - 1) {
+			let price = powerup.get_price(players.len() - 1, &self.settings.powerup_prices);
+			if coins_chasers < price {
 				return Err(Box::new(crate::CustomError::NotEnoughCoins));
 			}
 
-			coins_chasers -= powerup.get_price(players.len() - 1);
+			coins_chasers -= price;
 
 			match powerup {
 				Powerup::LearnRunnerCountry => {
-					move_result.power_up_status.runner_country = Some(players.iter().find(|x| x.id == self.runner).unwrap().current_location.into());
+					move_result.power_up_status.runner_country = Some(Country::try_from(players.iter().find(|x| x.id == self.runner).unwrap().current_location)?);
 				},
 				Powerup::LearnRunnerLocation => {
 					move_result.power_up_status.runner_location = Some(players.iter().find(|x| x.id == self.runner).unwrap().current_location);
@@ -276,6 +332,7 @@ impl InProgressGame {
 			};
 		}
 
+		let mut challenge_drawn: Option<EventCard> = None;
 		if move_made.buy_event_card {
 			if !in_progress_move.new_location_already_sent {
 				return Err(Box::new(crate::CustomError::EventCardNoLocationSent));
@@ -290,11 +347,11 @@ impl InProgressGame {
 			}
 
 			if player.id == self.runner {
-				if coins_runner < 1 {
+				if coins_runner < self.settings.event_card_price {
 					return Err(Box::new(crate::CustomError::NotEnoughCoins));
 				}
 			} else {
-				if coins_chasers < 1 {
+				if coins_chasers < self.settings.event_card_price {
 					return Err(Box::new(crate::CustomError::NotEnoughCoins));
 				}
 			}
@@ -305,7 +362,6 @@ impl InProgressGame {
 			}
 
 			let mut instantly_play_event_card = false;
-
 			match event_card.as_ref().unwrap() {
 				EventCard::GiveMeYourCards => {
 					let cloned_players = players.clone();
@@ -321,6 +377,7 @@ impl InProgressGame {
 							return x;
 						}).collect();
 
+						random_outcomes.push(RandomOutcome::EventCardTakenFrom(random_player_with_event_cards.id, random_event_card.clone()));
 						event_card = Some(random_event_card.clone());
 					} else {
 						event_card = event_card_stack.pop();
@@ -333,7 +390,7 @@ impl InProgressGame {
 
 				EventCard::HuntedByMenForSport => {
 					instantly_play_event_card = true;
-					player.must_use_fastest_transport_for_rounds = 2;
+					player.must_use_fastest_transport_for_rounds = self.settings.hunted_by_men_for_sport_rounds;
 				},
 				EventCard::LuxembourgIsGermanyFrance => {
 					instantly_play_event_card = true;
@@ -347,9 +404,6 @@ impl InProgressGame {
 					instantly_play_event_card = true;
 					player.can_use_any_train_ticket = true;
 				},
-				EventCard::HydrateOrDiedrate => {
-					instantly_play_event_card = true;
-				},
 				EventCard::StealthOutfit => {
 					instantly_play_event_card = true;
 					player.stealth_mode_active = true;
@@ -361,12 +415,14 @@ impl InProgressGame {
 				},
 				EventCard::Pizzazz => {
 					instantly_play_event_card = true;
-					let mut rng = thread_rng();
-					let coins_for_runner = rng.gen_range(1..=6);
-					let mut coins_for_chasers = 0;
-					for _ in 0..players.len() - 1 {
-						coins_for_chasers += rng.gen_range(1..=6);
+					let coins_for_runner = history::roll_die(&mut rng, self.turn, self.runner, RollReason::Pizzazz, &mut move_result.dice_rolls);
+					let mut chaser_rolls = Vec::new();
+					for chaser in players.iter().filter(|x| x.id != self.runner) {
+						chaser_rolls.push(history::roll_die(&mut rng, self.turn, chaser.id, RollReason::Pizzazz, &mut move_result.dice_rolls));
 					}
+					let coins_for_chasers: usize = chaser_rolls.iter().sum();
+					random_outcomes.push(RandomOutcome::PizzazzRunnerRoll(coins_for_runner));
+					random_outcomes.push(RandomOutcome::PizzazzChaserRolls(chaser_rolls));
 
 					if self.runner == player.id {
 						move_result.coins_received = Some(coins_for_runner);
@@ -379,11 +435,7 @@ impl InProgressGame {
 				},
 				EventCard::RatMode => {
 					instantly_play_event_card = true;
-					player.must_use_slowest_transport_for_rounds = 2;
-				},
-				EventCard::BingBong => {
-					instantly_play_event_card = true;
-					//Bing Bong
+					player.must_use_slowest_transport_for_rounds = self.settings.rat_mode_rounds;
 				},
 				EventCard::LeaveCountryImmediately => {
 					instantly_play_event_card = true;
@@ -393,19 +445,10 @@ impl InProgressGame {
 					instantly_play_event_card = true;
 					player.zug_faellt_aus_active = true;
 				},
-				EventCard::SnackZone => {
-					instantly_play_event_card = true;
-				},
 				EventCard::ItsAllInTheTrees => {
 					instantly_play_event_card = true;
 					get_extra_turns = 1;
 				},
-				EventCard::BonjourToEveryone => {
-					instantly_play_event_card = true;
-				},
-				EventCard::NoTalk => {
-					instantly_play_event_card = true;
-				},
 				EventCard::SloveniaAsATreat => {
 					instantly_play_event_card = true;
 					player.slovenia_as_a_treat_active = true;
@@ -413,6 +456,13 @@ impl InProgressGame {
 				_ => (),
 			}
 
+			//give_me_your_cards can end up with a challenge too, so this waits until the card is settled
+			if challenge::is_challenge(event_card.as_ref().unwrap()) {
+				instantly_play_event_card = true;
+				player.muted = player.muted || event_card == Some(EventCard::NoTalk);
+				challenge_drawn = event_card.clone();
+			}
+
 			in_progress_move.event_card_bought = true;
 			move_result.event_card_bought = true;
 			event_card_bought = true;
@@ -460,31 +510,55 @@ impl InProgressGame {
 		self.players = players;
 		self.event_card_bought = event_card_bought;
 		self.event_card_stack = event_card_stack;
+		self.rng = rng;
+		if let Some(dice_roll) = move_result.dice_rolls.last() {
+			self.dice_result = Some(dice_roll.roll);
+		}
+		self.dice_rolls.extend(move_result.dice_rolls.clone());
+
+		self.players = self.players.clone().into_iter().map(|mut x| {
+			if x.id == player.id {
+				x = player.clone();
+			}
+			return x;
+		}).collect();
+
+		if let Some(event_card) = challenge_drawn {
+			move_result.challenge = Some(self.start_challenge(player.id, event_card));
+		}
 
 		if move_made.finish_move {
 			self.in_progress_move = None;
+			self.turn += 1;
+			self.turn_started_at = history::now();
+			self.players.iter_mut().find(|x| x.id == move_made.player_id).unwrap().timeouts = 0;
 
-			//Write next player into self.current_turn
 			if self.get_extra_turns == 0 {
-				let current_players_position = self.players.iter().position(|x| x.id == move_made.player_id).unwrap();
-				if current_players_position == self.players.len() - 1 {
-					self.current_turn = self.players.first().unwrap().id;
-				} else {
-					self.current_turn = self.players.iter().nth(current_players_position + 1).unwrap().id;
-				}
+				self.advance_turn();
 			} else {
 				self.get_extra_turns -= 1;
 			}
 		}
 
-		self.players = self.players.clone().into_iter().map(|mut x| {
-			if x.id == player.id {
-				x = player.clone();
+		return Ok(move_result);
+	}
+
+	// Writes the next player into self.current_turn, skipping everyone who has to sit out a turn
+	fn advance_turn(&mut self) {
+		let mut position = self.players.iter().position(|x| x.id == self.current_turn).unwrap();
+		for _ in 0..self.players.len() {
+			position = (position + 1) % self.players.len();
+			let next_player = &mut self.players[position];
+
+			if next_player.skip_turns == 0 {
+				break;
 			}
-			return x;
-		}).collect();
+			next_player.skip_turns -= 1;
+		}
 
-		return Ok(move_result);
+		let next_player = &mut self.players[position];
+		next_player.muted = false;
+		self.current_turn = next_player.id;
 	}
 }
 
@@ -560,33 +634,34 @@ fn player_used_fastest_transport_method(used_timetable_card: &TimetableCard, pla
 }
 
 fn player_can_leave_country(player: &Player) -> bool {
+	let current_country = Country::try_from(player.current_location).ok();
 	let mut can_leave_country = false;
 	for timetable_card in &player.timetable_cards {
 		match timetable_card {
 			TimetableCard::LowSpeed => {
 				for connection in player.current_location.get_low_speed_connections() {
-					if Country::from(connection) != Country::from(player.current_location) {
+					if Country::try_from(connection).ok() != current_country {
 						can_leave_country = true;
 					}
 				}
 			},
 			TimetableCard::HighSpeed => {
 				for connection in player.current_location.get_high_speed_connections() {
-					if Country::from(connection) != Country::from(player.current_location) {
+					if Country::try_from(connection).ok() != current_country {
 						can_leave_country = true;
 					}
 				}
 			},
 			TimetableCard::Plane => {
 				for connection in player.current_location.get_plane_connections() {
-					if Country::from(connection) != Country::from(player.current_location) {
+					if Country::try_from(connection).ok() != current_country {
 						can_leave_country = true;
 					}
 				}
 			},
 			TimetableCard::Joker => {
 				for connection in player.current_location.get_joker_connections() {
-					if Country::from(connection) != Country::from(player.current_location) {
+					if Country::try_from(connection).ok() != current_country {
 						can_leave_country = true;
 					}
 				}
@@ -596,7 +671,7 @@ fn player_can_leave_country(player: &Player) -> bool {
 	return can_leave_country;
 }
 
-fn is_move_possible(player: &Player) -> bool {
+pub(super) fn is_move_possible(player: &Player) -> bool {
 	let mut move_possible = false;
 
 	for timetable_card in &player.timetable_cards {
@@ -633,7 +708,8 @@ fn remove_used_timetable_card_from_player(mut player: Player, timetable_card_use
 }
 
 
-#[derive(Debug, Clone, serde::Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, Default)]
+#[serde(default)]
 pub struct Move {
 	pub player_id: PlayerId,
 	pub next_location: Option<String>,
@@ -647,7 +723,7 @@ pub struct Move {
 	pub finish_move: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct InProgressMove {
 	pub new_location_already_sent: bool,
 	pub use_timetable_card_already_sent: bool,
@@ -655,13 +731,20 @@ pub struct InProgressMove {
 	pub stealth_mode_enabled: bool,
 }
 
-#[derive(Debug, Clone, Default, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub struct MoveResult {
 	pub coins_received: Option<usize>,
 	pub event_card_received: Option<EventCard>,
 	pub event_card_bought: bool,
 	pub runner_caught: bool,
 	pub timetable_cards_received: Vec<TimetableCard>,
+	//only used to hand the finished game to Game::make_move, it contains the session tokens of all players
+	#[serde(skip)]
 	pub finished_game: Option<FinishedGame>,
 	pub power_up_status: PowerupStatus,
+	//set when the bought event card is a challenge the other players have to vote on
+	#[serde(default)]
+	pub challenge: Option<ChallengeId>,
+	#[serde(default)]
+	pub dice_rolls: Vec<DiceRoll>,
 }
\ No newline at end of file
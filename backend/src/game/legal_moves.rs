@@ -0,0 +1,80 @@
+use super::*;
+use in_progress_game::{InProgressGame, Move};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LegalMove {
+	pub timetable_card: Option<TimetableCard>,
+	pub next_location: Location,
+}
+
+impl InProgressGame {
+	// Every (timetable card, destination) pair make_move would accept from the player right now.
+	// Each candidate is tried on a copy of the game, so the list can't disagree with make_move.
+	// A timetable card of None means the move is made without one (beach mode, slovenia as a treat, ...).
+	pub fn legal_moves(&self, player_id: PlayerId) -> Vec<LegalMove> {
+		if player_id != self.current_turn || self.in_progress_move.as_ref().is_some_and(|x| x.new_location_already_sent) {
+			return Vec::new();
+		}
+
+		let player = match self.players.iter().find(|x| x.id == player_id) {
+			Some(player) => player,
+			None => return Vec::new(),
+		};
+
+		let mut timetable_cards = player.timetable_cards.clone();
+		timetable_cards.sort();
+		timetable_cards.dedup();
+
+		let mut connections = player.current_location.get_joker_connections();
+		connections.sort();
+		connections.dedup();
+
+		let mut candidates: Vec<LegalMove> = Vec::new();
+		for timetable_card in &timetable_cards {
+			for connection in &connections {
+				candidates.push(LegalMove { timetable_card: Some(timetable_card.clone()), next_location: *connection });
+			}
+		}
+
+		if player.lets_go_to_the_beach_active {
+			for location in Location::get_iter().filter(|x| x.is_coastal()) {
+				candidates.push(LegalMove { timetable_card: None, next_location: location });
+			}
+		}
+
+		// make_move lets the player go anywhere without a card when there is no way north
+		if player.next_move_must_go_north_active && player.current_location.get_north_connections().is_empty() {
+			for connection in &connections {
+				candidates.push(LegalMove { timetable_card: None, next_location: *connection });
+			}
+		}
+
+		if player.slovenia_as_a_treat_active {
+			candidates.push(LegalMove { timetable_card: None, next_location: Location::Ljubljana });
+		}
+
+		let mut legal_moves: Vec<LegalMove> = candidates.into_iter().filter(|x| self.is_legal_move(player_id, x)).collect();
+		legal_moves.sort();
+		legal_moves.dedup();
+
+		return legal_moves;
+	}
+
+	fn is_legal_move(&self, player_id: PlayerId, legal_move: &LegalMove) -> bool {
+		let mut game = self.clone();
+		game.history = Vec::new();
+
+		let move_made = Move {
+			player_id,
+			next_location: Some(legal_move.next_location.to_string()),
+			use_timetable_card: legal_move.timetable_card.as_ref().map(|x| x.to_string()),
+			..Default::default()
+		};
+
+		//moves that end the game return before in_progress_move gets updated
+		return match game.apply_move(move_made, &mut Vec::new()) {
+			Ok(move_result) => move_result.finished_game.is_some() || game.in_progress_move.is_some_and(|x| x.new_location_already_sent),
+			Err(_) => false,
+		};
+	}
+}
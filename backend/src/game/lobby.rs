@@ -1,23 +1,41 @@
 use super::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Lobby {
 	pub id: GameId,
 	pub invite_code: String,
 	pub host: PlayerId,
 	pub players: Vec<Player>,
+	pub seed: u64,
+	pub rng: GameRng,
+	#[serde(default)]
+	pub spectators: Spectators,
+	//the game can only be started once every player is ready
+	#[serde(default)]
+	pub require_ready: bool,
+	#[serde(default)]
+	pub settings: GameSettings,
+	//unix time in milliseconds of the last change, see game_store/sweeper.rs
+	#[serde(default)]
+	pub last_activity: u64,
+	//set by a rematch that rotates the runner, a random player runs if this player left the lobby
+	#[serde(default)]
+	pub next_runner: Option<PlayerId>,
 }
 
 impl Lobby {
-	pub fn create(display_name: String) -> Self {
+	pub fn create(display_name: String, seed: Option<u64>) -> Self {
+		//the invite code doesn't come from the games rng, otherwise every game with the same seed would get the same code
 		let mut rng = thread_rng();
 		let invite_code_part1 = rng.gen_range(0..=999);
 		let invite_code_part2 = rng.gen_range(0..=999);
+		let seed = seed.unwrap_or_else(|| rng.gen());
 
 		let player_id = PlayerId::new_v4();
 		let player = Player {
 			id: player_id.clone(),
 			display_name,
+			session_token: Some(generate_session_token()),
 			..Default::default()
 		};
 
@@ -26,63 +44,181 @@ impl Lobby {
 			invite_code: format!("{invite_code_part1:0>3}-{invite_code_part2:0>3}"), //TODO: collision possible
 			host: player_id,
 			players: vec![player],
+			seed,
+			rng: GameRng::seed_from_u64(seed),
+			spectators: Spectators::default(),
+			require_ready: false,
+			settings: GameSettings::default(),
+			last_activity: history::now(),
+			next_runner: None,
 		}
 	}
 
 	pub fn join(&mut self, display_name: String) -> Result<PlayerId, Box<dyn Error>> {
-		if self.players.len() >= 4 {
+		if self.players.len() >= self.settings.max_players {
 			return Err(Box::new(crate::CustomError::LobbyFull));
 		}
+
+		if self.display_name_taken(&display_name) {
+			return Err(Box::new(crate::CustomError::DisplayNameTaken));
+		}
 		
 		let id = PlayerId::new_v4();
 		let player = Player {
 			id: id.clone(),
 			display_name,
+			session_token: Some(generate_session_token()),
 			..Default::default()
 		};
 
 		self.players.push(player);
+
+		//everyone left the lobby before, so whoever comes next takes over
+		if !self.players.iter().any(|x| x.id == self.host) {
+			self.host = id;
+		}
 		
 		return Ok(id);
 	}
 
+	// Only the host can add bots, they take the free seats like any other player
+	pub fn add_bot(&mut self, player_id: PlayerId, difficulty: BotDifficulty) -> Result<PlayerId, Box<dyn Error>> {
+		if player_id != self.host {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		let display_name = (1..).map(|x| format!("Bot {x}")).find(|x| !self.display_name_taken(x)).unwrap();
+		let id = self.join(display_name)?;
+
+		let bot = self.players.iter_mut().find(|x| x.id == id).unwrap();
+		bot.bot = Some(difficulty);
+		bot.ready = true;
+		//nobody plays in the name of a bot
+		bot.session_token = None;
+
+		return Ok(id);
+	}
+
+	// When the host leaves the next human becomes host, bots can't start the game.
+	// If only bots are left they get removed as well and whoever joins next becomes host.
+	pub fn leave(&mut self, player_id: PlayerId) -> Result<(), Box<dyn Error>> {
+		if !self.players.iter().any(|x| x.id == player_id) {
+			return Err(Box::new(crate::CustomError::UnknownPlayer));
+		}
+
+		self.players.retain(|x| x.id != player_id);
+
+		if !self.players.iter().any(|x| x.bot.is_none()) {
+			self.players.clear();
+		} else if player_id == self.host {
+			self.host = self.players.iter().find(|x| x.bot.is_none()).unwrap().id;
+		}
+
+		return Ok(());
+	}
+
+	pub fn kick(&mut self, player_id: PlayerId, kicked_player_id: PlayerId) -> Result<(), Box<dyn Error>> {
+		if player_id != self.host || kicked_player_id == self.host {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		return self.leave(kicked_player_id);
+	}
+
+	pub fn transfer_host(&mut self, player_id: PlayerId, new_host: PlayerId) -> Result<(), Box<dyn Error>> {
+		if player_id != self.host {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		match self.players.iter().find(|x| x.id == new_host) {
+			Some(player) if player.bot.is_some() => return Err(Box::new(crate::CustomError::ActionNotAllowed)),
+			Some(_) => self.host = new_host,
+			None => return Err(Box::new(crate::CustomError::UnknownPlayer)),
+		}
+
+		return Ok(());
+	}
+
+	pub fn set_ready(&mut self, player_id: PlayerId, ready: bool) -> Result<(), Box<dyn Error>> {
+		match self.players.iter_mut().find(|x| x.id == player_id) {
+			Some(player) => player.ready = ready,
+			None => return Err(Box::new(crate::CustomError::UnknownPlayer)),
+		}
+
+		return Ok(());
+	}
+
+	pub fn set_require_ready(&mut self, player_id: PlayerId, require_ready: bool) -> Result<(), Box<dyn Error>> {
+		if player_id != self.host {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		self.require_ready = require_ready;
+
+		return Ok(());
+	}
+
+	// Names are shown next to each other, so two players whose names only differ in case or spaces can't be told apart
+	fn display_name_taken(&self, display_name: &str) -> bool {
+		return self.players.iter().any(|x| x.display_name.trim().to_lowercase() == display_name.trim().to_lowercase());
+	}
+
 	pub fn start(&mut self, player_id: PlayerId) -> Result<InProgressGame, Box<dyn Error>> {
 		if player_id != self.host {
 			return Err(Box::new(crate::CustomError::ActionNotAllowed));
 		}
 		
-		if self.players.len() <= 2 {
+		if self.players.len() < self.settings.min_players {
 			return Err(Box::new(crate::CustomError::LobbyNotFullEnough));
 		}
+
+		if self.require_ready && self.players.iter().any(|x| !x.ready) {
+			return Err(Box::new(crate::CustomError::NotEveryoneReady));
+		}
 		
-		let mut rng = thread_rng();
+		let mut rng = self.rng.clone();
 		let rand_player_id = rng.gen_range(0..=self.players.len() - 1);
-		let rand_destination_index = rng.gen_range(0..=4);
+		let rand_destination_index = rng.gen_range(0..self.settings.destinations.len());
 
-		let runner = self.players.iter().nth(rand_player_id).unwrap().clone().id;
+		let runner = match self.next_runner.filter(|x| self.players.iter().any(|y| y.id == *x)) {
+			Some(next_runner) => next_runner,
+			None => self.players.iter().nth(rand_player_id).unwrap().clone().id,
+		};
 
 		let mut game = InProgressGame {
 			id: self.id,
 			host: self.host,
 			runner,
 			players: self.players.clone(),
-			destination: [Location::Dublin, Location::Copenhagen, Location::Vienna, Location::Rome, Location::Madrid].into_iter().nth(rand_destination_index).unwrap(),
+			destination: self.settings.destinations[rand_destination_index],
 			current_turn: runner,
 			coins_runner: 0,
 			coins_chasers: 0,
 			last_used_timetable_card: None,
 			dice_result: None,
+			dice_rolls: Vec::new(),
 			event_card_bought: false,
 			runner_path: vec![],
 			in_progress_move: None,
-			timetable_card_stack: generate_timetable_card_stack(),
-			event_card_stack: generate_event_card_stack(),
+			timetable_card_stack: generate_timetable_card_stack(&mut rng),
+			event_card_stack: generate_event_card_stack(&mut rng),
 			power_up_status: PowerupStatus::default(),
-			get_extra_turns: if self.players.len() == 3 {1} else {2},
+			//the runner gets a head start of one extra turn for every chaser after the first
+			get_extra_turns: (self.players.len() - 2) as u8,
+			seed: self.seed,
+			rng,
+			turn: 1,
+			turn_started_at: history::now(),
+			history: Vec::new(),
+			spectators: self.spectators.clone(),
+			settings: self.settings.clone(),
+			challenges: Vec::new(),
+			last_activity: history::now(),
+			series: if self.settings.series {Some(Box::new(Series::new(&self.players, runner)))} else {None},
 		};		
 
 		game.players = self.players.clone().into_iter().map(|mut x| {
-			x.timetable_cards = vec![game.timetable_card_stack.pop().unwrap(), game.timetable_card_stack.pop().unwrap(), game.timetable_card_stack.pop().unwrap(), game.timetable_card_stack.pop().unwrap(), game.timetable_card_stack.pop().unwrap()];
+			x.timetable_cards = (0..self.settings.starting_timetable_cards).map(|_| game.timetable_card_stack.pop().unwrap()).collect();
 			return x;
 		}).collect();
 
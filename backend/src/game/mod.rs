@@ -5,6 +5,16 @@ pub mod rest_api;
 pub mod lobby;
 pub mod in_progress_game;
 pub mod finished_game;
+pub mod websocket;
+pub mod history;
+pub mod legal_moves;
+pub mod bot;
+pub mod spectator;
+pub mod settings;
+pub mod challenge;
+pub mod turn_timer;
+pub mod rematch;
+pub mod series;
 
 use uuid::Uuid;
 use std::collections::BTreeMap;
@@ -16,19 +26,101 @@ use crate::location::*;
 use lobby::Lobby;
 use in_progress_game::InProgressGame;
 use finished_game::FinishedGame;
+use history::{DiceRoll, HistoryEntry, RandomOutcome, RollReason};
+use bot::BotDifficulty;
+use spectator::Spectators;
+use settings::GameSettings;
+use challenge::{Challenge, ChallengeId};
+use rematch::Rematch;
+use series::{RoundResult, Series};
 use crate::powerup::*;
+use crate::metrics::METRICS;
+use crate::logging;
 
-type GameId = Uuid;
-type PlayerId = Uuid;
+pub type GameId = Uuid;
+pub type PlayerId = Uuid;
+// Every random decision in a game comes from its own GameRng, so a game can be replayed from its seed
+pub type GameRng = rand_chacha::ChaCha8Rng;
 
-#[derive(Debug, Clone)]
+// 256 bits from the os rng, long enough that guessing a token of another player isn't an option
+fn generate_session_token() -> String {
+	return format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Game {
 	Lobby(Lobby),
 	InProgress(InProgressGame),
 	Finished(FinishedGame),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, Default)]
+impl Game {
+	pub fn players(&self) -> &Vec<Player> {
+		return match self {
+			Game::Lobby(lobby) => &lobby.players,
+			Game::InProgress(in_progress_game) => &in_progress_game.players,
+			Game::Finished(finished_game) => &finished_game.players,
+		};
+	}
+
+	pub fn id(&self) -> GameId {
+		return match self {
+			Game::Lobby(lobby) => lobby.id,
+			Game::InProgress(in_progress_game) => in_progress_game.id,
+			Game::Finished(finished_game) => finished_game.id,
+		};
+	}
+
+	pub fn last_activity(&self) -> u64 {
+		return match self {
+			Game::Lobby(lobby) => lobby.last_activity,
+			Game::InProgress(in_progress_game) => in_progress_game.last_activity,
+			Game::Finished(finished_game) => finished_game.last_activity,
+		};
+	}
+
+	pub fn touch(&mut self, now: u64) {
+		match self {
+			Game::Lobby(lobby) => lobby.last_activity = now,
+			Game::InProgress(in_progress_game) => in_progress_game.last_activity = now,
+			Game::Finished(finished_game) => finished_game.last_activity = now,
+		}
+	}
+
+	// Every move of players, bots and the turn timer goes through here, so this is where moves get counted and logged
+	pub fn make_move(&mut self, move_made: in_progress_game::Move) -> Result<in_progress_game::MoveResult, Box<dyn Error>> {
+		let span = logging::move_span(&self.id(), &move_made);
+		let _entered = span.enter();
+
+		let res = match self {
+			Game::InProgress(in_progress_game) => in_progress_game.make_move(move_made),
+			Game::Lobby(_) => Err(Box::new(crate::CustomError::InvalidGameState) as Box<dyn Error>),
+			Game::Finished(_) => Err(Box::new(crate::CustomError::InvalidGameState) as Box<dyn Error>),
+		};
+
+		match &res {
+			Ok(move_result) => {
+				METRICS.record_move();
+				//serialized like the player gets it, the debug representation would contain the finished game with every session token
+				tracing::info!(result = %serde_json::to_string(move_result).unwrap_or_default(), "move made");
+				if let Some(finished_game) = &move_result.finished_game {
+					METRICS.record_finished_game(&finished_game.win_condition, &finished_game.winning_team);
+					tracing::info!(win_condition = ?finished_game.win_condition, winning_team = ?finished_game.winning_team, "game finished");
+					*self = Game::Finished(finished_game.clone());
+				}
+			},
+			Err(e) => {
+				let error_id = e.downcast_ref::<crate::CustomError>().map(|x| x.error_id()).unwrap_or("unknown");
+				METRICS.record_rejected_move(error_id);
+				tracing::info!(error_id, error = %e, "move rejected");
+			},
+		}
+
+		return res;
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
 pub struct Player {
 	id: Uuid,
 	display_name: String,
@@ -45,15 +137,40 @@ pub struct Player {
 	leave_country_immediately_active: bool,
 	zug_faellt_aus_active: bool,
 	slovenia_as_a_treat_active: bool,
+	bot: Option<BotDifficulty>,
+	//secret the player authenticates with, the id is public and can be shown to other players. Bots don't have one
+	session_token: Option<String>,
+	//only used in the lobby, bots are always ready
+	#[serde(default)]
+	ready: bool,
+	//turns the player has to sit out after failing a challenge
+	#[serde(default)]
+	skip_turns: u8,
+	//no_talk: the player isn't allowed to talk until their next turn
+	#[serde(default)]
+	muted: bool,
+	//turns in a row the player ran out of time
+	#[serde(default)]
+	timeouts: u8,
+}
+
+impl Player {
+	pub fn id(&self) -> PlayerId {
+		return self.id;
+	}
+
+	pub fn session_token(&self) -> Option<&str> {
+		return self.session_token.as_deref();
+	}
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Team {
 	Runner,
 	Chaser,
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum WinCondition {
 	RunnerCaught,
 	GotToDestination,
@@ -0,0 +1,51 @@
+use super::*;
+
+// The lobby that was created to play a finished game again
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Rematch {
+	pub game_id: GameId,
+	pub invite_code: String,
+	//players keep their ids, but sessions only work for one game, so every human gets a new token for the rematch
+	pub session_tokens: BTreeMap<PlayerId, String>,
+}
+
+impl FinishedGame {
+	// Turns the players of the finished game into a new lobby with the same host and settings.
+	// With rotate_runner the player after the last runner becomes the next runner instead of a random one
+	pub fn rematch(&mut self, player_id: PlayerId, rotate_runner: bool) -> Result<Lobby, Box<dyn Error>> {
+		if !self.players.iter().any(|x| x.id == player_id) {
+			return Err(Box::new(crate::CustomError::UnknownPlayer));
+		}
+
+		if self.rematch.is_some() {
+			return Err(Box::new(crate::CustomError::InvalidGameState));
+		}
+
+		//a fresh lobby, so the rematch gets its own invite codes and seed
+		let mut lobby = Lobby::create(String::new(), None);
+		lobby.host = self.host;
+		lobby.players = self.players.iter().map(|x| Player {
+			id: x.id,
+			display_name: x.display_name.clone(),
+			//players a bot took over after running out of time play themselves again
+			bot: if x.session_token.is_some() {None} else {x.bot},
+			session_token: x.session_token.as_ref().map(|_| generate_session_token()),
+			..Default::default()
+		}).collect();
+		lobby.settings = self.settings.clone();
+		lobby.spectators.broadcast_delay = self.spectators.broadcast_delay;
+
+		if rotate_runner {
+			let runner_index = self.players.iter().position(|x| x.id == self.runner).unwrap();
+			lobby.next_runner = Some(self.players[(runner_index + 1) % self.players.len()].id);
+		}
+
+		self.rematch = Some(Rematch {
+			game_id: lobby.id,
+			invite_code: lobby.invite_code.clone(),
+			session_tokens: lobby.players.iter().filter_map(|x| x.session_token().map(|token| (x.id, token.to_string()))).collect(),
+		});
+
+		return Ok(lobby);
+	}
+}
@@ -1,6 +1,13 @@
 use actix_web::{get, post, web, HttpResponse, Responder};
+use actix_web::ResponseError;
 use crate::rest_api::AppState;
+use crate::rest_api::error::error_response;
+use crate::rest_api::session::Session;
+use crate::CustomError;
+use crate::game_store::{GameHandle, GameStore};
+use super::spectator::Participant;
 use serde::{Deserialize, Serialize};
+use tokio::sync::OwnedMutexGuard;
 use super::*;
 
 #[derive(Deserialize, Debug, Clone)]
@@ -8,188 +15,990 @@ struct JoinGamePostBody {
   display_name: String,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+struct CreateGamePostBody {
+  display_name: String,
+  seed: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct JoinAsSpectatorPostBody {
+  display_name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct SpectatorSettingsPostBody {
+  broadcast_delay: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct PlayerPostBody {
+  player_id: PlayerId,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ReadyPostBody {
+  ready: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct RequireReadyPostBody {
+  require_ready: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ChallengeVotePostBody {
+  confirm: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct RematchPostBody {
+  #[serde(default)]
+  rotate_runner: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct AddBotPostBody {
+  difficulty: BotDifficulty,
+}
+
 #[post("/api/v1/games")]
-pub async fn create_game(data: web::Data<AppState>, body: web::Json<JoinGamePostBody>) -> impl Responder {
-	let game = Lobby::create(body.display_name.clone());
+pub async fn create_game(data: web::Data<AppState>, body: web::Json<CreateGamePostBody>) -> impl Responder {
+	if refuses_new_games(&data) {
+		return CustomError::ServerBusy.error_response();
+	}
 
-	match data.games.try_lock() {
-		Ok(mut games) => {
-			let game_id = game.id;
-			let invite_code = game.invite_code.clone();
-			let player_id = game.host;
+	let game = Lobby::create(body.display_name.clone(), body.seed);
+	let game_id = game.id;
+	let invite_code = game.invite_code.clone();
+	let player_id = game.host;
+	let session_token = game.players[0].session_token().unwrap().to_string();
+	let spectator_invite_code = game.spectators.invite_code.clone();
 
-			games.insert(game_id.clone(), Game::Lobby(game));
-			return HttpResponse::Ok().body(format!("{{\"game_id\":\"{game_id}\",\"invite_code\":\"{invite_code}\",\"player_id\":\"{player_id}\"}}"));
-		},
-		Err(e) => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"{e}\"}}")),
+	if let Err(e) = data.games.insert(Game::Lobby(game)) {
+		return error_response(e);
 	}
+	return HttpResponse::Ok().body(format!("{{\"game_id\":\"{game_id}\",\"invite_code\":\"{invite_code}\",\"spectator_invite_code\":\"{spectator_invite_code}\",\"player_id\":\"{player_id}\",\"session_token\":\"{session_token}\"}}"));
 }
 
 #[post("/api/v1/invites/{invite_code}/join")]
 pub async fn join_game(data: web::Data<AppState>, body: web::Json<JoinGamePostBody>, invite_code: web::Path<String>) -> impl Responder {
-	match data.games.try_lock() {
-		Ok(mut games) => {
-			match games.iter().filter(|x| {
-				match x.1 {
-					Game::Lobby(lobby) => lobby.invite_code == invite_code.clone(),
-					Game::InProgress(_) => false,
-					Game::Finished(_) => false,
-				}
-			}).map(|x| x.1).next() {
-				Some(game) => {
-					match game {
-						Game::Lobby(lobby) => {
-							let game_id = lobby.id;
-
-							let game = games.get_mut(&game_id).unwrap();
-							match game {
-								Game::Lobby(lobby) => {
-									match lobby.join(body.display_name.clone()) {
-										Ok(player_id) => return HttpResponse::Ok().body(format!("{{\"game_id\":\"{game_id}\",\"player_id\":\"{player_id}\"}}")),
-										Err(e) => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"{e}\"}}")),
-									}
-								},
-								Game::InProgress(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-								Game::Finished(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-							}
-						},
-						Game::InProgress(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-						Game::Finished(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-					};
+	let mut game = match data.games.find_lobby_by_invite_code(&invite_code) {
+		Some(game) => timed_lock(game).await,
+		None => return CustomError::InviteCodeNotFound.error_response(),
+	};
+	let game_id = game.id();
+
+	match &mut *game {
+		Game::Lobby(lobby) => {
+			match lobby.join(body.display_name.clone()) {
+				Ok(player_id) => {
+					let session_token = lobby.players.iter().find(|x| x.id == player_id).unwrap().session_token().unwrap().to_string();
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(&game_id);
+					return HttpResponse::Ok().body(format!("{{\"game_id\":\"{game_id}\",\"player_id\":\"{player_id}\",\"session_token\":\"{session_token}\"}}"));
+				},
+				Err(e) => return error_response(e),
+			}
+		},
+		//the game got started while waiting for the lock
+		Game::InProgress(_) => return CustomError::InviteCodeNotFound.error_response(),
+		Game::Finished(_) => return CustomError::InviteCodeNotFound.error_response(),
+	}
+}
+
+#[post("/api/v1/spectate/{spectator_invite_code}")]
+pub async fn join_as_spectator(data: web::Data<AppState>, body: web::Json<JoinAsSpectatorPostBody>, spectator_invite_code: web::Path<String>) -> impl Responder {
+	let mut game = match data.games.find_game_by_spectator_invite_code(&spectator_invite_code) {
+		Some(game) => timed_lock(game).await,
+		None => return CustomError::InviteCodeNotFound.error_response(),
+	};
+	let game_id = game.id();
+
+	let spectator_id = game.spectators_mut().join(body.display_name.clone());
+	let session_token = game.spectators().spectators.iter().find(|x| x.id == spectator_id).unwrap().session_token().to_string();
+	persist_game(data.games.as_ref(), &mut game);
+	drop(game);
+
+	return HttpResponse::Ok().body(format!("{{\"game_id\":\"{game_id}\",\"spectator_id\":\"{spectator_id}\",\"session_token\":\"{session_token}\"}}"));
+}
+
+#[post("/api/v1/games/{game_id}/spectator_settings")]
+pub async fn set_spectator_settings(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<SpectatorSettingsPostBody>) -> impl Responder {
+	return change_lobby(&data, &game_id, session, |lobby, player_id| lobby.set_broadcast_delay(player_id, body.broadcast_delay)).await;
+}
+
+#[post("/api/v1/games/{game_id}/settings")]
+pub async fn set_settings(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<SettingsView>) -> impl Responder {
+	let settings: GameSettings = match body.into_inner().try_into() {
+		Ok(settings) => settings,
+		Err(e) => return e.error_response(),
+	};
+
+	return change_lobby(&data, &game_id, session, |lobby, player_id| lobby.set_settings(player_id, settings)).await;
+}
+
+#[post("/api/v1/games/{game_id}/leave")]
+pub async fn leave_game(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session) -> impl Responder {
+	return change_lobby(&data, &game_id, session, |lobby, player_id| lobby.leave(player_id)).await;
+}
+
+#[post("/api/v1/games/{game_id}/kick")]
+pub async fn kick_player(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<PlayerPostBody>) -> impl Responder {
+	return change_lobby(&data, &game_id, session, |lobby, player_id| lobby.kick(player_id, body.player_id)).await;
+}
+
+#[post("/api/v1/games/{game_id}/host")]
+pub async fn transfer_host(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<PlayerPostBody>) -> impl Responder {
+	return change_lobby(&data, &game_id, session, |lobby, player_id| lobby.transfer_host(player_id, body.player_id)).await;
+}
+
+#[post("/api/v1/games/{game_id}/ready")]
+pub async fn set_ready(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<ReadyPostBody>) -> impl Responder {
+	return change_lobby(&data, &game_id, session, |lobby, player_id| lobby.set_ready(player_id, body.ready)).await;
+}
+
+#[post("/api/v1/games/{game_id}/require_ready")]
+pub async fn set_require_ready(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<RequireReadyPostBody>) -> impl Responder {
+	return change_lobby(&data, &game_id, session, |lobby, player_id| lobby.set_require_ready(player_id, body.require_ready)).await;
+}
+
+// Makes a change to the lobby in the name of the player the session belongs to, only works before the game is started
+async fn change_lobby(data: &AppState, game_id: &GameId, session: Session, change: impl FnOnce(&mut Lobby, PlayerId) -> Result<(), Box<dyn Error>>) -> HttpResponse {
+	let player_id = match session.player_id_in(game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(data, game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match &mut *game {
+		Game::Lobby(lobby) => {
+			match change(lobby, player_id) {
+				Ok(_) => {
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(game_id);
+					return HttpResponse::Ok().body("");
+				},
+				Err(e) => return error_response(e),
+			}
+		},
+		_ => return CustomError::InvalidGameState.error_response(),
+	}
+}
+
+#[post("/api/v1/games/{game_id}/bots")]
+pub async fn add_bot(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<AddBotPostBody>) -> impl Responder {
+	let player_id = match session.player_id_in(&game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match &mut *game {
+		Game::Lobby(lobby) => {
+			match lobby.add_bot(player_id, body.difficulty) {
+				Ok(bot_id) => {
+					let display_name = lobby.players.iter().find(|x| x.id == bot_id).unwrap().display_name.clone();
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(&game_id);
+					return HttpResponse::Ok().body(serde_json::json!({"player_id": bot_id, "display_name": display_name}).to_string());
 				},
-				None => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"no game with invite code {invite_code} found\"}}")),
+				Err(e) => return error_response(e),
 			}
 		},
-		Err(e) => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"{e}\"}}")),
+		_ => return CustomError::InvalidGameState.error_response(),
+	}
+}
+
+// Players are referred to by their player_id everywhere, the display name is only attached here
+#[derive(Debug, Clone, Serialize)]
+struct PlayerView {
+	player_id: PlayerId,
+	display_name: String,
+	bot: bool,
+	//no_talk, only for clients to show, the server doesn't enforce it
+	muted: bool,
+}
+
+impl From<&Player> for PlayerView {
+	fn from(value: &Player) -> Self {
+		return PlayerView {
+			player_id: value.id,
+			display_name: value.display_name.clone(),
+			bot: value.bot.is_some(),
+			muted: value.muted,
+		};
+	}
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SeriesView {
+	//starts at 1
+	round: usize,
+	rounds: usize,
+	runner_order: Vec<PlayerId>,
+	results: Vec<RoundResult>,
+	scoreboard: BTreeMap<PlayerId, usize>,
+	over: bool,
+}
+
+impl SeriesView {
+	fn new(series: &Series, round: usize) -> Self {
+		return SeriesView {
+			round,
+			rounds: series.runner_order.len(),
+			runner_order: series.runner_order.clone(),
+			results: series.results.clone(),
+			scoreboard: series.scoreboard(),
+			over: series.is_over(),
+		};
+	}
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ChallengeView {
+	challenge_id: ChallengeId,
+	player_id: PlayerId,
+	event_card: String,
+	turn: usize,
+	confirmed_by: Vec<PlayerId>,
+	rejected_by: Vec<PlayerId>,
+	outcome: Option<challenge::ChallengeOutcome>,
+}
+
+impl From<&Challenge> for ChallengeView {
+	fn from(value: &Challenge) -> Self {
+		return ChallengeView {
+			challenge_id: value.id,
+			player_id: value.player_id,
+			event_card: value.event_card.to_string(),
+			turn: value.turn,
+			confirmed_by: value.confirmed_by.clone(),
+			rejected_by: value.rejected_by.clone(),
+			outcome: value.outcome,
+		};
+	}
+}
+
+// Same as GameSettings, with locations as ids like everywhere else in the api.
+// Fields that are left out of a request get their default value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettingsView {
+	min_players: usize,
+	max_players: usize,
+	starting_timetable_cards: usize,
+	destinations: Vec<String>,
+	coins_needed_at_destination: usize,
+	event_card_price: usize,
+	hunted_by_men_for_sport_rounds: u8,
+	rat_mode_rounds: u8,
+	powerup_prices: PowerupPrices,
+	turn_time_limit: Option<u64>,
+	timeouts_until_bot: u8,
+	series: bool,
+}
+
+impl Default for SettingsView {
+	fn default() -> Self {
+		return SettingsView::from(&GameSettings::default());
+	}
+}
+
+impl From<&GameSettings> for SettingsView {
+	fn from(value: &GameSettings) -> Self {
+		return SettingsView {
+			min_players: value.min_players,
+			max_players: value.max_players,
+			starting_timetable_cards: value.starting_timetable_cards,
+			destinations: value.destinations.iter().map(|x| x.to_string()).collect(),
+			coins_needed_at_destination: value.coins_needed_at_destination,
+			event_card_price: value.event_card_price,
+			hunted_by_men_for_sport_rounds: value.hunted_by_men_for_sport_rounds,
+			rat_mode_rounds: value.rat_mode_rounds,
+			powerup_prices: value.powerup_prices.clone(),
+			turn_time_limit: value.turn_time_limit,
+			timeouts_until_bot: value.timeouts_until_bot,
+			series: value.series,
+		};
+	}
+}
+
+impl TryFrom<SettingsView> for GameSettings {
+	type Error = CustomError;
+	fn try_from(value: SettingsView) -> Result<GameSettings, CustomError> {
+		return Ok(GameSettings {
+			min_players: value.min_players,
+			max_players: value.max_players,
+			starting_timetable_cards: value.starting_timetable_cards,
+			destinations: value.destinations.into_iter().map(Location::try_from).collect::<Result<Vec<Location>, CustomError>>()?,
+			coins_needed_at_destination: value.coins_needed_at_destination,
+			event_card_price: value.event_card_price,
+			hunted_by_men_for_sport_rounds: value.hunted_by_men_for_sport_rounds,
+			rat_mode_rounds: value.rat_mode_rounds,
+			powerup_prices: value.powerup_prices,
+			turn_time_limit: value.turn_time_limit,
+			timeouts_until_bot: value.timeouts_until_bot,
+			series: value.series,
+		});
 	}
 }
 
 #[derive(Debug, Clone, Serialize)]
 struct LobbyGameState {
-	players: Vec<String>,
+	host: PlayerId,
+	players: Vec<PlayerView>,
+	//only sent to players
+	#[serde(skip_serializing_if = "Option::is_none")]
+	spectator_invite_code: Option<String>,
+	broadcast_delay: Option<usize>,
+	ready_players: Vec<PlayerId>,
+	require_ready: bool,
+	settings: SettingsView,
 }
 
 #[derive(Debug, Clone, Serialize)]
 struct InProgressGameState { //TODO: add size of timetable card stack
-	runner: String,
+	spectator_invite_code: String,
+	players: Vec<PlayerView>,
+	runner: PlayerId,
 	destination: Option<String>,
-	current_turn: String,
+	current_turn: PlayerId,
+	turn_deadline: Option<u64>,
 	coins_runner: usize,
 	coins_chasers: usize,
 	your_timetable_cards: Vec<String>,
-	chaser_timetable_cards: BTreeMap<String, Vec<String>>,
+	chaser_timetable_cards: BTreeMap<PlayerId, Vec<String>>,
 	last_used_timetable_card: String,
 	dice_result: Option<u8>,
+	dice_rolls: Vec<DiceRoll>,
 	event_card_bought: bool,
 	runner_current_country: String,
 	runner_current_location: String,
 	runner_destination: String,
 	chaser_gets_another_turn: bool,
-	chaser_locations: BTreeMap<String, String>,
+	chaser_locations: BTreeMap<PlayerId, String>,
 	your_current_location: String,
+	challenges: Vec<ChallengeView>,
+	settings: SettingsView,
+	series: Option<SeriesView>,
+}
+
+// What spectators see of a running game: the same as the chasers, without any hand of cards.
+// In broadcast mode everything that happened before the delay gets added
+#[derive(Debug, Clone, Serialize)]
+struct SpectatorGameState {
+	players: Vec<PlayerView>,
+	runner: PlayerId,
+	current_turn: PlayerId,
+	turn_deadline: Option<u64>,
+	turn: usize,
+	coins_runner: usize,
+	coins_chasers: usize,
+	last_used_timetable_card: Option<String>,
+	dice_result: Option<u8>,
+	dice_rolls: Vec<DiceRoll>,
+	event_card_bought: bool,
+	runner_current_country: Option<String>,
+	runner_current_location: Option<String>,
+	runner_destination: Option<String>,
+	chaser_gets_another_turn: bool,
+	chaser_locations: BTreeMap<PlayerId, String>,
+	broadcast: Option<BroadcastView>,
+	challenges: Vec<ChallengeView>,
+	settings: SettingsView,
+	series: Option<SeriesView>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BroadcastView {
+	delay: usize,
+	destination: Option<String>,
+	runner_path: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FinishedGameState {
+	players: Vec<PlayerView>,
+	runner: PlayerId,
+	destination: String,
+	coins_runner: usize,
+	coins_chasers: usize,
+	winning_team: Team,
+	win_condition: WinCondition,
+	runner_path: Vec<String>,
+	seed: u64,
+	dice_rolls: Vec<DiceRoll>,
+	rematch: Option<RematchView>,
+	series: Option<SeriesView>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-struct GetCurrentStateQueryOptions {
-	player_id: Option<Uuid>,
+#[derive(Debug, Clone, Serialize)]
+struct RematchView {
+	game_id: GameId,
+	//only sent to players, spectators have to be invited again
+	invite_code: Option<String>,
+	session_token: Option<String>,
 }
 
 #[get("/api/v1/games/{game_id}/current_state")]
-pub async fn get_current_state(data: web::Data<AppState>, game_id: web::Path<Uuid>, query: web::Query<GetCurrentStateQueryOptions>) -> impl Responder {
-	//TODO: return error when no player_id is set as query
-	match data.games.try_lock() {
-		Ok(games) => {
-			match games.get(&game_id) {
-				Some(game) => {
-					let current_state_json = match game {
-						Game::Lobby(game) => serde_json::to_string(&LobbyGameState {players: game.players.iter().map(|x| x.display_name.clone()).collect()}),
-						Game::InProgress(game) => serde_json::to_string(&InProgressGameState {
-							runner: game.players.iter().find(|x| x.id == game.runner).unwrap().display_name.clone(),
-							destination: if query.player_id.is_some_and(|x| x == game.runner) {Some(game.destination.clone().to_string())} else {None},
-							current_turn: game.players.iter().find(|x| x.id == game.current_turn).unwrap().display_name.clone(),
-							coins_runner: game.coins_runner,
-							coins_chasers: game.coins_chasers,
-							your_timetable_cards: game.players.iter().find(|x| x.id == query.player_id.unwrap()).unwrap().timetable_cards.iter().map(|x| x.to_string()).collect(),
-							chaser_timetable_cards: game.players.iter().filter(|x| x.id != game.runner).map(|x| (x.display_name.clone(), x.timetable_cards.iter().map(|x| x.to_string()).collect())).collect(),
-							last_used_timetable_card: if game.last_used_timetable_card.is_some() {game.last_used_timetable_card.clone().unwrap().to_string()} else {String::new()},
-							dice_result: game.dice_result,
-							event_card_bought: game.event_card_bought,
-							runner_current_country: if game.power_up_status.runner_country.is_some() {game.power_up_status.runner_country.unwrap().to_string()} else {String::default()},
-							runner_current_location: if game.power_up_status.runner_location.is_some() {game.power_up_status.runner_location.unwrap().to_string()} else {String::default()},
-							runner_destination: if game.power_up_status.runner_destination.is_some() {game.power_up_status.runner_destination.unwrap().to_string()} else {String::default()},
-							chaser_gets_another_turn: game.power_up_status.get_another_turn,
-							chaser_locations: game.players.iter().filter(|x| x.id != game.runner && !x.stealth_mode_active).map(|x| (x.display_name.clone(), x.current_location.to_string())).collect(),
-							your_current_location: game.players.iter().find(|x| x.id == query.player_id.unwrap()).unwrap().current_location.to_string(),
-						}),
-						Game::Finished(game) => serde_json::to_string(game),
-					}.unwrap();
-					return HttpResponse::Ok().body(current_state_json);
+pub async fn get_current_state(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Option<Session>) -> impl Responder {
+	let participant = match session.map(|x| x.participant_in(&game_id)).transpose() {
+		Ok(participant) => participant,
+		Err(e) => return e.error_response(),
+	};
+
+	let game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match current_state_for(&game, participant) {
+		Ok(current_state) => return HttpResponse::Ok().body(current_state.to_string()),
+		Err(e) => return e.error_response(),
+	}
+}
+
+pub fn current_state_for(game: &Game, participant: Option<Participant>) -> Result<serde_json::Value, CustomError> {
+	return match participant {
+		Some(Participant::Spectator(_)) => Ok(spectator_state(game)),
+		Some(Participant::Player(player_id)) => current_state(game, Some(player_id)),
+		None => current_state(game, None),
+	};
+}
+
+// Builds the view of the game the given player is allowed to see
+pub fn current_state(game: &Game, player_id: Option<PlayerId>) -> Result<serde_json::Value, CustomError> {
+	return Ok(match game {
+		Game::Lobby(game) => serde_json::to_value(&LobbyGameState {
+			host: game.host,
+			players: game.players.iter().map(PlayerView::from).collect(),
+			spectator_invite_code: if game.players.iter().any(|x| Some(x.id) == player_id) {Some(game.spectators.invite_code.clone())} else {None},
+			broadcast_delay: game.spectators.broadcast_delay,
+			ready_players: game.players.iter().filter(|x| x.ready).map(|x| x.id).collect(),
+			require_ready: game.require_ready,
+			settings: SettingsView::from(&game.settings),
+		}),
+		Game::InProgress(game) => {
+			let player = match player_id {
+				Some(player_id) => match game.players.iter().find(|x| x.id == player_id) {
+					Some(player) => player,
+					None => return Err(CustomError::UnknownPlayer),
 				},
-				None => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"no game with id {game_id} found\"}}")),
+				None => return Err(CustomError::MissingSessionToken),
+			};
+
+			serde_json::to_value(&InProgressGameState {
+				spectator_invite_code: game.spectators.invite_code.clone(),
+				players: game.players.iter().map(PlayerView::from).collect(),
+				runner: game.runner,
+				destination: if player.id == game.runner {Some(game.destination.clone().to_string())} else {None},
+				current_turn: game.current_turn,
+				turn_deadline: game.turn_deadline(),
+				coins_runner: game.coins_runner,
+				coins_chasers: game.coins_chasers,
+				your_timetable_cards: player.timetable_cards.iter().map(|x| x.to_string()).collect(),
+				chaser_timetable_cards: game.players.iter().filter(|x| x.id != game.runner).map(|x| (x.id, x.timetable_cards.iter().map(|x| x.to_string()).collect())).collect(),
+				last_used_timetable_card: if game.last_used_timetable_card.is_some() {game.last_used_timetable_card.clone().unwrap().to_string()} else {String::new()},
+				dice_result: game.dice_result,
+				dice_rolls: game.dice_rolls.clone(),
+				event_card_bought: game.event_card_bought,
+				runner_current_country: if game.power_up_status.runner_country.is_some() {game.power_up_status.runner_country.unwrap().to_string()} else {String::default()},
+				runner_current_location: if game.power_up_status.runner_location.is_some() {game.power_up_status.runner_location.unwrap().to_string()} else {String::default()},
+				runner_destination: if game.power_up_status.runner_destination.is_some() {game.power_up_status.runner_destination.unwrap().to_string()} else {String::default()},
+				chaser_gets_another_turn: game.power_up_status.get_another_turn,
+				chaser_locations: game.players.iter().filter(|x| x.id != game.runner && !x.stealth_mode_active).map(|x| (x.id, x.current_location.to_string())).collect(),
+				your_current_location: player.current_location.to_string(),
+				challenges: game.challenges.iter().map(ChallengeView::from).collect(),
+				settings: SettingsView::from(&game.settings),
+				series: game.series.as_ref().map(|x| SeriesView::new(x, x.results.len() + 1)),
+			})
+		},
+		Game::Finished(game) => return Ok(finished_game_state(game, player_id)),
+	}.unwrap());
+}
+
+// Builds the view spectators get, the lobby and the finished game look the same as for players
+pub fn spectator_state(game: &Game) -> serde_json::Value {
+	let game = match game {
+		Game::InProgress(game) => game,
+		Game::Lobby(_) => return current_state(game, None).unwrap(),
+		Game::Finished(game) => return finished_game_state(game, None),
+	};
+
+	return serde_json::to_value(&SpectatorGameState {
+		players: game.players.iter().map(PlayerView::from).collect(),
+		runner: game.runner,
+		current_turn: game.current_turn,
+		turn_deadline: game.turn_deadline(),
+		turn: game.turn,
+		coins_runner: game.coins_runner,
+		coins_chasers: game.coins_chasers,
+		last_used_timetable_card: game.last_used_timetable_card.as_ref().map(|x| x.to_string()),
+		dice_result: game.dice_result,
+		dice_rolls: game.dice_rolls.clone(),
+		event_card_bought: game.event_card_bought,
+		runner_current_country: game.power_up_status.runner_country.map(|x| x.to_string()),
+		runner_current_location: game.power_up_status.runner_location.map(|x| x.to_string()),
+		runner_destination: game.power_up_status.runner_destination.map(|x| x.to_string()),
+		chaser_gets_another_turn: game.power_up_status.get_another_turn,
+		chaser_locations: game.players.iter().filter(|x| x.id != game.runner && !x.stealth_mode_active).map(|x| (x.id, x.current_location.to_string())).collect(),
+		broadcast: game.spectators.broadcast_delay.map(|delay| BroadcastView {
+			delay,
+			destination: game.destination_for_spectators().map(|x| x.to_string()),
+			runner_path: game.runner_path_for_spectators().unwrap_or_default().iter().map(|x| x.to_string()).collect(),
+		}),
+		challenges: game.challenges.iter().map(ChallengeView::from).collect(),
+		settings: SettingsView::from(&game.settings),
+		series: game.series.as_ref().map(|x| SeriesView::new(x, x.results.len() + 1)),
+	}).unwrap();
+}
+
+fn finished_game_state(game: &FinishedGame, player_id: Option<PlayerId>) -> serde_json::Value {
+	let is_player = game.players.iter().any(|x| Some(x.id) == player_id);
+
+	return serde_json::to_value(&FinishedGameState {
+		players: game.players.iter().map(PlayerView::from).collect(),
+		runner: game.runner,
+		destination: game.destination.to_string(),
+		coins_runner: game.coins_runner,
+		coins_chasers: game.coins_chasers,
+		winning_team: game.winning_team.clone(),
+		win_condition: game.win_condition.clone(),
+		runner_path: game.runner_path.iter().map(|x| x.to_string()).collect(),
+		seed: game.seed,
+		dice_rolls: game.dice_rolls.clone(),
+		rematch: game.rematch.as_ref().map(|x| RematchView {
+			game_id: x.game_id,
+			invite_code: if is_player {Some(x.invite_code.clone())} else {None},
+			session_token: player_id.and_then(|player_id| x.session_tokens.get(&player_id).cloned()),
+		}),
+		series: game.series.as_ref().map(|x| SeriesView::new(x, x.results.len())),
+	}).unwrap();
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct HistoryEntryView {
+	turn: usize,
+	player_id: PlayerId,
+	timestamp: u64,
+	next_location: Option<String>,
+	use_timetable_card: Option<String>,
+	buy_event_card: bool,
+	use_event_card: Option<String>,
+	buy_powerup: Option<String>,
+	throw_timetable_cards_away: Vec<String>,
+	finish_move: bool,
+	coins_received: Option<usize>,
+	event_card_received: Option<String>,
+	timetable_cards_received: Vec<String>,
+	runner_caught: bool,
+	runner_country: Option<String>,
+	runner_location: Option<String>,
+	runner_destination: Option<String>,
+	challenge: Option<ChallengeId>,
+	dice_rolls: Vec<DiceRoll>,
+	random_outcomes: Vec<RandomOutcomeView>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RandomOutcomeView {
+	CoinFieldRoll { roll: usize },
+	PizzazzRunnerRoll { roll: usize },
+	PizzazzChaserRolls { rolls: Vec<usize> },
+	EventCardTakenFrom { player_id: PlayerId, event_card: String },
+}
+
+#[get("/api/v1/games/{game_id}/history")]
+pub async fn get_history(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Option<Session>) -> impl Responder {
+	let participant = match session.map(|x| x.participant_in(&game_id)).transpose() {
+		Ok(participant) => participant,
+		Err(e) => return e.error_response(),
+	};
+
+	let game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	let history = match participant {
+		Some(Participant::Spectator(_)) => Ok(spectator_history(&game)),
+		Some(Participant::Player(player_id)) => history(&game, Some(player_id)),
+		None => history(&game, None),
+	};
+
+	match history {
+		Ok(history) => return HttpResponse::Ok().body(history.to_string()),
+		Err(e) => return e.error_response(),
+	}
+}
+
+// Every move made so far, filtered to what the player may know while the game is running and complete once it's finished
+pub fn history(game: &Game, player_id: Option<PlayerId>) -> Result<serde_json::Value, CustomError> {
+	let history = match game {
+		Game::Lobby(_) => return Ok(serde_json::Value::Array(Vec::new())),
+		Game::InProgress(game) => {
+			let player_id = match player_id {
+				Some(player_id) => player_id,
+				None => return Err(CustomError::MissingSessionToken),
+			};
+			if !game.players.iter().any(|x| x.id == player_id) {
+				return Err(CustomError::UnknownPlayer);
 			}
+
+			game.history_for(player_id)
 		},
-		Err(e) => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"{e}\"}}")),
+		Game::Finished(game) => game.history.clone(),
+	};
+
+	return Ok(history_view(history));
+}
+
+// The history spectators may see, in broadcast mode moves get revealed completely once they are older than the delay
+pub fn spectator_history(game: &Game) -> serde_json::Value {
+	return match game {
+		Game::InProgress(game) => history_view(game.history_for_spectators()),
+		_ => history(game, None).unwrap(),
+	};
+}
+
+fn history_view(history: Vec<HistoryEntry>) -> serde_json::Value {
+	let history: Vec<HistoryEntryView> = history.into_iter().map(|x| HistoryEntryView {
+		turn: x.turn,
+		player_id: x.player_id,
+		timestamp: x.timestamp,
+		next_location: x.move_made.next_location,
+		use_timetable_card: x.move_made.use_timetable_card,
+		buy_event_card: x.move_made.buy_event_card,
+		use_event_card: x.move_made.use_event_card,
+		buy_powerup: x.move_made.buy_powerup,
+		throw_timetable_cards_away: x.move_made.throw_timetable_cards_away,
+		finish_move: x.move_made.finish_move,
+		coins_received: x.move_result.coins_received,
+		event_card_received: x.move_result.event_card_received.map(|x| x.to_string()),
+		timetable_cards_received: x.move_result.timetable_cards_received.iter().map(|x| x.to_string()).collect(),
+		runner_caught: x.move_result.runner_caught,
+		runner_country: x.move_result.power_up_status.runner_country.map(|x| x.to_string()),
+		runner_location: x.move_result.power_up_status.runner_location.map(|x| x.to_string()),
+		runner_destination: x.move_result.power_up_status.runner_destination.map(|x| x.to_string()),
+		challenge: x.move_result.challenge,
+		dice_rolls: x.move_result.dice_rolls,
+		random_outcomes: x.random_outcomes.into_iter().map(|x| match x {
+			RandomOutcome::CoinFieldRoll(roll) => RandomOutcomeView::CoinFieldRoll { roll },
+			RandomOutcome::PizzazzRunnerRoll(roll) => RandomOutcomeView::PizzazzRunnerRoll { roll },
+			RandomOutcome::PizzazzChaserRolls(rolls) => RandomOutcomeView::PizzazzChaserRolls { rolls },
+			RandomOutcome::EventCardTakenFrom(player_id, event_card) => RandomOutcomeView::EventCardTakenFrom { player_id, event_card: event_card.to_string() },
+		}).collect(),
+	}).collect();
+
+	return serde_json::to_value(history).unwrap();
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LegalMovesView {
+	legal_moves: Vec<LegalMoveView>,
+	can_throw_timetable_cards_away: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LegalMoveView {
+	use_timetable_card: Option<String>,
+	next_location: String,
+}
+
+#[get("/api/v1/games/{game_id}/legal_moves")]
+pub async fn get_legal_moves(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Option<Session>) -> impl Responder {
+	let player_id = match session.map(|x| x.player_id_in(&game_id)).transpose() {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match legal_moves(&game, player_id) {
+		Ok(legal_moves) => return HttpResponse::Ok().body(legal_moves.to_string()),
+		Err(e) => return e.error_response(),
 	}
 }
 
-#[derive(Deserialize, Debug, Clone)]
-struct StartGamePostBody {
-  player_id: Uuid,
+// Every move the player could send to make_move right now, empty when it isn't their turn or they already moved
+pub fn legal_moves(game: &Game, player_id: Option<PlayerId>) -> Result<serde_json::Value, CustomError> {
+	let game = match game {
+		Game::InProgress(game) => game,
+		_ => return Err(CustomError::InvalidGameState),
+	};
+
+	let player = match player_id {
+		Some(player_id) => match game.players.iter().find(|x| x.id == player_id) {
+			Some(player) => player,
+			None => return Err(CustomError::UnknownPlayer),
+		},
+		None => return Err(CustomError::MissingSessionToken),
+	};
+
+	let legal_moves = game.legal_moves(player.id);
+	let already_moved = game.in_progress_move.as_ref().is_some_and(|x| x.new_location_already_sent);
+
+	return Ok(serde_json::to_value(LegalMovesView {
+		can_throw_timetable_cards_away: player.id == game.current_turn && !already_moved && !in_progress_game::is_move_possible(player),
+		legal_moves: legal_moves.into_iter().map(|x| LegalMoveView {
+			use_timetable_card: x.timetable_card.map(|x| x.to_string()),
+			next_location: x.next_location.to_string(),
+		}).collect(),
+	}).unwrap());
 }
+
 #[post("/api/v1/games/{game_id}/start")]
-pub async fn start_game(data: web::Data<AppState>, game_id: web::Path<Uuid>, body: web::Json<StartGamePostBody>) -> impl Responder {
-	match data.games.try_lock() {
-		Ok(mut games) => {
-			match games.get_mut(&game_id) {
-				Some(game) => {
-					match game {
-						Game::Lobby(lobby) => {
-							match lobby.start(body.player_id) {
-								Ok(in_progress_game) => {
-									*game = Game::InProgress(in_progress_game);
-									return HttpResponse::Ok().body("")
-								},
-								Err(e) => return HttpResponse::from_error(e),
-						}},
-						Game::InProgress(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-						Game::Finished(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-					};
+pub async fn start_game(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session) -> impl Responder {
+	let player_id = match session.player_id_in(&game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match &mut *game {
+		Game::Lobby(lobby) => {
+			match lobby.start(player_id) {
+				Ok(in_progress_game) => {
+					*game = Game::InProgress(in_progress_game);
+					game.play_bot_turns();
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(&game_id);
+					return HttpResponse::Ok().body("")
 				},
-				None => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"no game with id {game_id} found\"}}")),
+				Err(e) => return error_response(e),
 			}
 		},
-		Err(e) => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"{e}\"}}")),
-	}
+		Game::InProgress(_) => return CustomError::InvalidGameState.error_response(),
+		Game::Finished(_) => return CustomError::InvalidGameState.error_response(),
+	};
 }
 
 #[post("/api/v1/games/{game_id}/make_move")]
-pub async fn make_move(data: web::Data<AppState>, game_id: web::Path<Uuid>, body: web::Json<crate::game::in_progress_game::Move>) -> impl Responder {
-	match data.games.try_lock() {
-		Ok(mut games) => {
-			match games.get_mut(&game_id) {
-				Some(game) => {
-					match game {
-						Game::Lobby(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-						Game::InProgress(in_progress_game) => {
-							match in_progress_game.make_move(body.into_inner()) {
-								Ok(res) => {
-									if res.finished_game.is_some() {
-										*game = Game::Finished(res.clone().finished_game.unwrap());
-									}
-									return HttpResponse::Ok().body(serde_json::to_string(&res).unwrap())
-								},
-								Err(e) => {
-									println!("error in make_move: {e}");
-									return HttpResponse::from_error(e)
-								},
-							}
-						},
-						Game::Finished(_) => return HttpResponse::BadRequest().body("you cant do that while the game is in its current state"),
-					};
+pub async fn make_move(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<crate::game::in_progress_game::Move>) -> impl Responder {
+	let mut move_made = body.into_inner();
+	move_made.player_id = match session.player_id_in(&game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match *game {
+		Game::Lobby(_) => return CustomError::InvalidGameState.error_response(),
+		Game::InProgress(_) => {
+			match game.make_move(move_made) {
+				Ok(res) => {
+					game.play_bot_turns();
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(&game_id);
+					return HttpResponse::Ok().body(serde_json::to_string(&res).unwrap())
+				},
+				Err(e) => return error_response(e),
+			}
+		},
+		Game::Finished(_) => return CustomError::InvalidGameState.error_response(),
+	};
+}
+
+// Ends the bot takeover after running out of time too often, see turn_timer.rs
+#[post("/api/v1/games/{game_id}/take_back")]
+pub async fn take_back(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session) -> impl Responder {
+	let player_id = match session.player_id_in(&game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match &mut *game {
+		Game::InProgress(in_progress_game) => {
+			match in_progress_game.take_back(player_id) {
+				Ok(_) => {
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(&game_id);
+					return HttpResponse::Ok().body("");
+				},
+				Err(e) => return error_response(e),
+			}
+		},
+		_ => return CustomError::InvalidGameState.error_response(),
+	}
+}
+
+// Starts the next round of a series, see series.rs
+#[post("/api/v1/games/{game_id}/next_round")]
+pub async fn next_round(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session) -> impl Responder {
+	let player_id = match session.player_id_in(&game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match &mut *game {
+		Game::Finished(finished_game) => {
+			match finished_game.next_round(player_id) {
+				Ok(in_progress_game) => {
+					*game = Game::InProgress(in_progress_game);
+					game.play_bot_turns();
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(&game_id);
+					return HttpResponse::Ok().body("");
 				},
-				None => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"no game with id {game_id} found\"}}")),
+				Err(e) => return error_response(e),
 			}
 		},
-		Err(e) => return HttpResponse::InternalServerError().body(format!("{{\"error\":\"{e}\"}}")),
+		_ => return CustomError::InvalidGameState.error_response(),
 	}
-}
\ No newline at end of file
+}
+
+// Creates the lobby for playing the finished game again, or returns it when another player was faster, see rematch.rs
+#[post("/api/v1/games/{game_id}/rematch")]
+pub async fn rematch(data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session, body: web::Json<RematchPostBody>) -> impl Responder {
+	let player_id = match session.player_id_in(&game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	let finished_game = match &mut *game {
+		Game::Finished(finished_game) => finished_game,
+		_ => return CustomError::InvalidGameState.error_response(),
+	};
+
+	let created = finished_game.rematch.is_none();
+	if created {
+		if refuses_new_games(&data) {
+			return CustomError::ServerBusy.error_response();
+		}
+
+		let lobby = match finished_game.rematch(player_id, body.rotate_runner) {
+			Ok(lobby) => lobby,
+			Err(e) => return error_response(e),
+		};
+
+		if let Err(e) = data.games.insert(Game::Lobby(lobby)) {
+			finished_game.rematch = None;
+			return error_response(e);
+		}
+
+		persist_game(data.games.as_ref(), &mut game);
+	}
+
+	let rematch = match &*game {
+		Game::Finished(finished_game) => finished_game.rematch.clone().unwrap(),
+		_ => unreachable!(),
+	};
+	drop(game);
+
+	if created {
+		data.game_updates.notify(&game_id);
+	}
+
+	let rematch_id = rematch.game_id;
+	let invite_code = rematch.invite_code;
+	let session_token = rematch.session_tokens.get(&player_id).cloned().unwrap_or_default();
+	return HttpResponse::Ok().body(format!("{{\"game_id\":\"{rematch_id}\",\"invite_code\":\"{invite_code}\",\"player_id\":\"{player_id}\",\"session_token\":\"{session_token}\"}}"));
+}
+
+// Confirms or rejects a challenge another player drew, see challenge.rs
+#[post("/api/v1/games/{game_id}/challenges/{challenge_id}")]
+pub async fn vote_on_challenge(data: web::Data<AppState>, path: web::Path<(Uuid, Uuid)>, session: Session, body: web::Json<ChallengeVotePostBody>) -> impl Responder {
+	let (game_id, challenge_id) = path.into_inner();
+	let player_id = match session.player_id_in(&game_id) {
+		Ok(player_id) => player_id,
+		Err(e) => return e.error_response(),
+	};
+
+	let mut game = match lock_game(&data, &game_id).await {
+		Ok(game) => game,
+		Err(e) => return e.error_response(),
+	};
+
+	match &mut *game {
+		Game::InProgress(in_progress_game) => {
+			match in_progress_game.vote_on_challenge(player_id, challenge_id, body.confirm) {
+				Ok(_) => {
+					persist_game(data.games.as_ref(), &mut game);
+					drop(game);
+					data.game_updates.notify(&game_id);
+					return HttpResponse::Ok().body("");
+				},
+				Err(e) => return error_response(e),
+			}
+		},
+		_ => return CustomError::InvalidGameState.error_response(),
+	}
+}
+
+// Waits until no other request uses the game anymore
+pub async fn lock_game(data: &AppState, game_id: &GameId) -> Result<OwnedMutexGuard<Game>, CustomError> {
+	crate::logging::record_game(game_id);
+	return match data.games.get(game_id) {
+		Some(game) => Ok(timed_lock(game).await),
+		None => Err(CustomError::GameNotFound),
+	};
+}
+
+// Locks the game and records how long that took
+pub async fn timed_lock(game: GameHandle) -> OwnedMutexGuard<Game> {
+	let started_at = std::time::Instant::now();
+	let game = game.lock_owned().await;
+	METRICS.record_lock_wait(started_at.elapsed());
+
+	return game;
+}
+
+// New games can't be created while the server shuts down or once it keeps as many games as the config allows
+fn refuses_new_games(data: &AppState) -> bool {
+	if data.shutting_down.load(std::sync::atomic::Ordering::SeqCst) {
+		return true;
+	}
+
+	return data.config.limits.max_games.is_some_and(|x| data.games.len() >= x);
+}
+
+// Every persisted change counts as activity, games without any get removed by the sweeper
+pub fn persist_game(games: &dyn GameStore, game: &mut Game) {
+	game.touch(history::now());
+	if let Err(e) = games.persist(game) {
+		tracing::error!(game_id = %game.id(), error = %e, "couldn't persist game");
+	}
+}
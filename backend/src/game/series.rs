@@ -0,0 +1,107 @@
+use super::*;
+
+// Points the runner gets for reaching the destination, on top of the turns survived and the coins
+pub const DESTINATION_BONUS: usize = 10;
+
+// A game where every player is the runner once. Every round is a game of its own under the same game id,
+// the runners get a score for how long they lasted and how far they got
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Series {
+	pub runner_order: Vec<PlayerId>,
+	//the seeds of the rounds after the first one come from this. It never gets sent to anyone,
+	//finished rounds show their seed and the next round mustn't be predictable from it
+	pub seed: u64,
+	pub results: Vec<RoundResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RoundResult {
+	pub round: usize,
+	pub runner: PlayerId,
+	pub turns_survived: usize,
+	pub coins: usize,
+	pub reached_destination: bool,
+	pub score: usize,
+}
+
+impl Series {
+	// The players run in the order they sit in, starting with the runner of the first round
+	pub fn new(players: &[Player], runner: PlayerId) -> Series {
+		let runner_index = players.iter().position(|x| x.id == runner).unwrap();
+
+		return Series {
+			runner_order: (0..players.len()).map(|i| players[(runner_index + i) % players.len()].id).collect(),
+			//not from the games rng, that one is known to everyone who knows the seed of the first round
+			seed: thread_rng().gen(),
+			results: Vec::new(),
+		};
+	}
+
+	pub fn is_over(&self) -> bool {
+		return self.results.len() >= self.runner_order.len();
+	}
+
+	pub fn record_round(&mut self, game: &InProgressGame, win_condition: &WinCondition) {
+		let reached_destination = *win_condition == WinCondition::GotToDestination;
+
+		self.results.push(RoundResult {
+			round: self.results.len() + 1,
+			runner: game.runner,
+			turns_survived: game.turn,
+			coins: game.coins_runner,
+			reached_destination,
+			score: game.turn + game.coins_runner + if reached_destination {DESTINATION_BONUS} else {0},
+		});
+	}
+
+	// The summed up score of every player, players that didn't run yet have 0
+	pub fn scoreboard(&self) -> BTreeMap<PlayerId, usize> {
+		let mut scoreboard: BTreeMap<PlayerId, usize> = self.runner_order.iter().map(|x| (*x, 0)).collect();
+		for result in &self.results {
+			*scoreboard.entry(result.runner).or_default() += result.score;
+		}
+
+		return scoreboard;
+	}
+}
+
+impl FinishedGame {
+	// Starts the next round of the series with the next runner, only the host can do that.
+	// Players keep their sessions, everything else starts over like in a new lobby
+	pub fn next_round(&self, player_id: PlayerId) -> Result<InProgressGame, Box<dyn Error>> {
+		if player_id != self.host {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		let series = match &self.series {
+			Some(series) if !series.is_over() => series,
+			_ => return Err(Box::new(crate::CustomError::InvalidGameState)),
+		};
+
+		let seed = GameRng::seed_from_u64(series.seed.wrapping_add(series.results.len() as u64)).gen();
+		let mut lobby = Lobby {
+			id: self.id,
+			invite_code: String::new(),
+			host: self.host,
+			players: self.players.iter().map(|x| Player {
+				id: x.id,
+				display_name: x.display_name.clone(),
+				bot: x.bot,
+				session_token: x.session_token.clone(),
+				..Default::default()
+			}).collect(),
+			seed,
+			rng: GameRng::seed_from_u64(seed),
+			spectators: self.spectators.clone(),
+			require_ready: false,
+			settings: self.settings.clone(),
+			last_activity: history::now(),
+			next_runner: Some(series.runner_order[series.results.len()]),
+		};
+
+		let mut game = lobby.start(self.host)?;
+		game.series = Some(Box::new(series.clone()));
+
+		return Ok(game);
+	}
+}
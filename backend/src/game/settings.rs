@@ -0,0 +1,109 @@
+use super::*;
+
+// Rules the host can change while the game is still a lobby, the defaults are the rules of the board game
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+	pub min_players: usize,
+	pub max_players: usize,
+	pub starting_timetable_cards: usize,
+	//the runner gets one of these as destination
+	pub destinations: Vec<Location>,
+	//coins the runner needs when arriving at the destination to win
+	pub coins_needed_at_destination: usize,
+	pub event_card_price: usize,
+	pub hunted_by_men_for_sport_rounds: u8,
+	pub rat_mode_rounds: u8,
+	pub powerup_prices: PowerupPrices,
+	//seconds a player has for their turn before the server finishes it for them, None: no limit
+	pub turn_time_limit: Option<u64>,
+	//turns in a row that have to run out of time before a bot takes over the player
+	pub timeouts_until_bot: u8,
+	//every player is the runner once and gets a score for it, see series.rs
+	pub series: bool,
+}
+
+// More players would need more timetable cards than there are in the stack
+pub const MAX_PLAYERS: usize = 6;
+pub const MAX_STARTING_TIMETABLE_CARDS: usize = 10;
+// The timer only checks once a second, shorter limits wouldn't leave time to play
+pub const MIN_TURN_TIME_LIMIT: u64 = 10;
+// A day, nobody waits longer than that for a turn
+pub const MAX_TURN_TIME_LIMIT: u64 = 24 * 60 * 60;
+
+impl Default for GameSettings {
+	fn default() -> Self {
+		return GameSettings {
+			min_players: 3,
+			max_players: 4,
+			starting_timetable_cards: 5,
+			destinations: DESTINATIONS.to_vec(),
+			coins_needed_at_destination: 10,
+			event_card_price: 1,
+			hunted_by_men_for_sport_rounds: 2,
+			rat_mode_rounds: 2,
+			powerup_prices: PowerupPrices::default(),
+			turn_time_limit: None,
+			timeouts_until_bot: 3,
+			series: false,
+		};
+	}
+}
+
+impl GameSettings {
+	pub fn validate(&self) -> Result<(), crate::CustomError> {
+		if self.min_players < 2 {
+			return Err(crate::CustomError::InvalidSettings("min_players has to be at least 2, a runner and a chaser".to_string()));
+		}
+
+		if self.max_players < self.min_players || self.max_players > MAX_PLAYERS {
+			return Err(crate::CustomError::InvalidSettings(format!("max_players has to be between min_players and {MAX_PLAYERS}")));
+		}
+
+		//players without cards lose right away
+		if self.starting_timetable_cards < 1 || self.starting_timetable_cards > MAX_STARTING_TIMETABLE_CARDS {
+			return Err(crate::CustomError::InvalidSettings(format!("starting_timetable_cards has to be between 1 and {MAX_STARTING_TIMETABLE_CARDS}")));
+		}
+
+		if self.destinations.is_empty() {
+			return Err(crate::CustomError::InvalidSettings("there has to be at least one destination".to_string()));
+		}
+
+		if self.destinations.iter().enumerate().any(|(i, x)| self.destinations[..i].contains(x)) {
+			return Err(crate::CustomError::InvalidSettings("destinations can't contain a location twice".to_string()));
+		}
+
+		//the loaded board can leave out locations the server knows about
+		if let Some(destination) = self.destinations.iter().find(|x| board::board().node(x).is_err()) {
+			return Err(crate::CustomError::InvalidSettings(format!("destination {destination} isn't on the board")));
+		}
+
+		if self.turn_time_limit.is_some_and(|x| !(MIN_TURN_TIME_LIMIT..=MAX_TURN_TIME_LIMIT).contains(&x)) {
+			return Err(crate::CustomError::InvalidSettings(format!("turn_time_limit has to be between {MIN_TURN_TIME_LIMIT} and {MAX_TURN_TIME_LIMIT} seconds")));
+		}
+
+		if self.timeouts_until_bot < 1 {
+			return Err(crate::CustomError::InvalidSettings("timeouts_until_bot has to be at least 1".to_string()));
+		}
+
+		return Ok(());
+	}
+}
+
+impl Lobby {
+	pub fn set_settings(&mut self, player_id: PlayerId, settings: GameSettings) -> Result<(), Box<dyn Error>> {
+		if player_id != self.host {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		settings.validate()?;
+
+		if self.players.len() > settings.max_players {
+			return Err(Box::new(crate::CustomError::InvalidSettings("there are already more players in the lobby than max_players".to_string())));
+		}
+
+		self.settings = settings;
+
+		return Ok(());
+	}
+}
@@ -0,0 +1,131 @@
+use super::*;
+
+pub type SpectatorId = Uuid;
+
+// Whoever a session token belongs to. Spectators can watch the game, but never act in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Participant {
+	Player(PlayerId),
+	Spectator(SpectatorId),
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Spectators {
+	//works in every phase of the game, unlike the invite code of the lobby
+	pub invite_code: String,
+	//None: spectators never learn more than the chasers know.
+	//Some(n): broadcast mode, every move gets revealed completely once n more turns have been finished after it
+	pub broadcast_delay: Option<usize>,
+	pub spectators: Vec<Spectator>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Spectator {
+	pub id: SpectatorId,
+	pub display_name: String,
+	session_token: String,
+}
+
+// Longer delays would keep the runner hidden for the whole game anyway
+pub const MAX_BROADCAST_DELAY: usize = 100;
+
+impl Default for Spectators {
+	fn default() -> Self {
+		return Spectators {
+			//long enough that it can be shared as a link without anyone guessing it
+			invite_code: Uuid::new_v4().simple().to_string(),
+			broadcast_delay: None,
+			spectators: Vec::new(),
+		};
+	}
+}
+
+impl Spectators {
+	pub fn join(&mut self, display_name: String) -> SpectatorId {
+		let id = SpectatorId::new_v4();
+		self.spectators.push(Spectator {
+			id,
+			display_name,
+			session_token: generate_session_token(),
+		});
+
+		return id;
+	}
+}
+
+impl Spectator {
+	pub fn session_token(&self) -> &str {
+		return &self.session_token;
+	}
+}
+
+impl Game {
+	pub fn spectators(&self) -> &Spectators {
+		return match self {
+			Game::Lobby(lobby) => &lobby.spectators,
+			Game::InProgress(in_progress_game) => &in_progress_game.spectators,
+			Game::Finished(finished_game) => &finished_game.spectators,
+		};
+	}
+
+	pub fn spectators_mut(&mut self) -> &mut Spectators {
+		return match self {
+			Game::Lobby(lobby) => &mut lobby.spectators,
+			Game::InProgress(in_progress_game) => &mut in_progress_game.spectators,
+			Game::Finished(finished_game) => &mut finished_game.spectators,
+		};
+	}
+}
+
+impl Lobby {
+	// Has to be decided before the game starts, changing it later could reveal the runner right away
+	pub fn set_broadcast_delay(&mut self, player_id: PlayerId, broadcast_delay: Option<usize>) -> Result<(), Box<dyn Error>> {
+		if player_id != self.host {
+			return Err(Box::new(crate::CustomError::ActionNotAllowed));
+		}
+
+		if broadcast_delay.is_some_and(|x| x > MAX_BROADCAST_DELAY) {
+			return Err(Box::new(crate::CustomError::InvalidSettings(format!("broadcast_delay can't be more than {MAX_BROADCAST_DELAY} turns"))));
+		}
+
+		self.spectators.broadcast_delay = broadcast_delay;
+
+		return Ok(());
+	}
+}
+
+impl InProgressGame {
+	// Everything spectators are allowed to see of the history: the same as the chasers,
+	// plus every move that is older than the broadcast delay when broadcast mode is on
+	pub fn history_for_spectators(&self) -> Vec<HistoryEntry> {
+		return self.history.iter().map(|x| {
+			if self.revealed_to_spectators(x.turn) {
+				return x.clone();
+			}
+
+			//nobody has the nil id, so spectators get what every chaser gets
+			return x.visible_to(SpectatorId::nil(), self.runner);
+		}).collect();
+	}
+
+	// The path of the runner as far as broadcast mode has revealed it, None when broadcast mode is off
+	pub fn runner_path_for_spectators(&self) -> Option<Vec<Location>> {
+		self.spectators.broadcast_delay?;
+
+		return Some(self.history.iter()
+			.filter(|x| x.player_id == self.runner && self.revealed_to_spectators(x.turn))
+			.filter_map(|x| x.move_made.next_location.as_ref().and_then(|x| Location::try_from(x.clone()).ok()))
+			.collect());
+	}
+
+	pub fn destination_for_spectators(&self) -> Option<Location> {
+		return if self.revealed_to_spectators(0) {Some(self.destination)} else {None};
+	}
+
+	fn revealed_to_spectators(&self, turn: usize) -> bool {
+		return match self.spectators.broadcast_delay {
+			Some(broadcast_delay) => self.turn > turn.saturating_add(broadcast_delay),
+			None => false,
+		};
+	}
+}
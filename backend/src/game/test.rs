@@ -1,6 +1,83 @@
 use super::*;
 use super::lobby::Lobby;
 use super::in_progress_game::Move;
+use super::settings::GameSettings;
+
+// A started game of three players where the host is the runner and has the first turn and nobody gets extra turns.
+// Every test that needs such a game builds it with this, the builder methods set what the test depends on
+struct TestGame {
+	settings: GameSettings,
+	//the second and the third player get added with add_bot instead of joining
+	bots: Option<BotDifficulty>,
+	broadcast_delay: Option<usize>,
+	//locations and timetable cards of the host, the second and the third player
+	players: [(Location, Vec<TimetableCard>); 3],
+	//replaces the shuffled stack, so the cards players draw are known
+	timetable_card_stack: Option<Vec<TimetableCard>>,
+}
+
+impl TestGame {
+	fn new() -> Self {
+		return Self {
+			settings: GameSettings::default(),
+			bots: None,
+			broadcast_delay: None,
+			players: [(Location::Paris, vec![TimetableCard::LowSpeed; 5]), (Location::Munich, vec![TimetableCard::LowSpeed; 5]), (Location::Munich, vec![TimetableCard::LowSpeed; 5])],
+			timetable_card_stack: None,
+		};
+	}
+
+	fn settings(mut self, settings: GameSettings) -> Self {
+		self.settings = settings;
+		return self;
+	}
+
+	fn bots(mut self, difficulty: BotDifficulty) -> Self {
+		self.bots = Some(difficulty);
+		return self;
+	}
+
+	fn broadcast_delay(mut self, broadcast_delay: usize) -> Self {
+		self.broadcast_delay = Some(broadcast_delay);
+		return self;
+	}
+
+	fn player(mut self, index: usize, location: Location, timetable_cards: Vec<TimetableCard>) -> Self {
+		self.players[index] = (location, timetable_cards);
+		return self;
+	}
+
+	fn timetable_card_stack(mut self, timetable_card_stack: Vec<TimetableCard>) -> Self {
+		self.timetable_card_stack = Some(timetable_card_stack);
+		return self;
+	}
+
+	fn start(self) -> (InProgressGame, PlayerId, PlayerId) {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let (player2, player3) = match self.bots {
+			Some(difficulty) => (lobby.add_bot(lobby.host, difficulty).unwrap(), lobby.add_bot(lobby.host, difficulty).unwrap()),
+			None => (lobby.join("test_2".to_string()).unwrap(), lobby.join("test_3".to_string()).unwrap()),
+		};
+		lobby.set_settings(lobby.host, self.settings).unwrap();
+		lobby.set_broadcast_delay(lobby.host, self.broadcast_delay).unwrap();
+		let mut game = lobby.start(lobby.host).unwrap();
+
+		game.runner = game.host;
+		game.current_turn = game.host;
+		game.get_extra_turns = 0;
+		//turn time limits count from here
+		game.turn_started_at = 1_000_000;
+		if let Some(timetable_card_stack) = self.timetable_card_stack {
+			game.timetable_card_stack = timetable_card_stack;
+		}
+		for (player, (location, timetable_cards)) in game.players.iter_mut().zip(self.players) {
+			player.current_location = location;
+			player.timetable_cards = timetable_cards;
+		}
+
+		return (game, player2, player3);
+	}
+}
 
 mod create {
 	use super::*;
@@ -8,7 +85,7 @@ mod create {
 	#[test]
 	fn properly_adds_first_player() {
 		let display_name = "test".to_string();
-		let res = Lobby::create(display_name.clone());
+		let res = Lobby::create(display_name.clone(), None);
 
 		assert_eq!(res.players.clone().first().unwrap().display_name, display_name);
 		assert_eq!(res.players.clone().first().unwrap().id, res.host);
@@ -20,7 +97,7 @@ mod join {
 
 	#[test]
 	fn join_adds_2nd_player() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 
 		let player_id = game.join("test_2".to_string());
 
@@ -30,7 +107,7 @@ mod join {
 
 	#[test]
 	fn join_adds_3rd_player() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 
 		let player_id = game.join("test_3".to_string());
@@ -41,7 +118,7 @@ mod join {
 
 	#[test]
 	fn join_adds_4th_player() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 
@@ -53,7 +130,7 @@ mod join {
 
 	#[test]
 	fn join_doesnt_add_5th_player() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 		let _ = game.join("test_4".to_string());
@@ -63,6 +140,17 @@ mod join {
 		assert!(res.is_err());
 		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::LobbyFull.to_string());
 	}
+
+	#[test]
+	fn join_doesnt_add_player_with_taken_name() {
+		let mut game = Lobby::create("Leon".to_string(), None);
+
+		let res = game.join(" leon ".to_string());
+
+		assert!(res.is_err());
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::DisplayNameTaken.to_string());
+		assert_eq!(game.players.len(), 1);
+	}
 }
 
 mod start {
@@ -70,7 +158,7 @@ mod start {
 
 	#[test]
 	fn start_with_3_players() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 
@@ -81,7 +169,7 @@ mod start {
 
 	#[test]
 	fn start_with_4_players() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 		let _ = game.join("test_4".to_string());
@@ -93,7 +181,7 @@ mod start {
 
 	#[test]
 	fn start_with_2_players_fails() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 
 		let res = game.start(game.host);
@@ -104,7 +192,7 @@ mod start {
 
 	#[test]
 	fn other_player_cant_start() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let player = game.join("test_3".to_string());
 
@@ -120,7 +208,7 @@ mod start {
 
 		let mut output: BTreeMap<String, usize> = BTreeMap::new();
 		for _ in 0..n {
-			let mut game = Lobby::create("test_1".to_string());
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let _ = game.join("test_2".to_string());
 			let _ = game.join("test_3".to_string());
 			let game = game.start(game.host).unwrap();
@@ -135,7 +223,7 @@ mod start {
 
 	#[test]
 	fn runner_gets_first_turn() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 		let game = game.start(game.host).unwrap();
@@ -145,7 +233,7 @@ mod start {
 
 	#[test]
 	fn each_player_gets_5_cards() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 		let game = game.start(game.host).unwrap();
@@ -155,7 +243,7 @@ mod start {
 
 	#[test]
 	fn timetable_card_stack_gets_filled() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 		let game = game.start(game.host).unwrap();
@@ -166,7 +254,7 @@ mod start {
 
 	#[test]
 	fn event_card_stack_gets_filled() {
-		let mut game = Lobby::create("test_1".to_string());
+		let mut game = Lobby::create("test_1".to_string(), None);
 		let _ = game.join("test_2".to_string());
 		let _ = game.join("test_3".to_string());
 		let game = game.start(game.host).unwrap();
@@ -180,7 +268,7 @@ mod start {
 
 		let mut output: BTreeMap<Location, usize> = BTreeMap::new();
 		for _ in 0..n {
-			let mut game = Lobby::create("test_1".to_string());
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let _ = game.join("test_2".to_string());
 			let _ = game.join("test_3".to_string());
 			let game = game.start(game.host).unwrap();
@@ -194,1249 +282,1428 @@ mod start {
 	}
 }
 
-mod make_move {
+mod lobby_management {
 	use super::*;
+	use crate::game::bot::BotDifficulty;
 
 	#[test]
-	fn sets_in_progress_move_when_its_none() {
-		let mut game = Lobby::create("test_1".to_string());
-		let _ = game.join("test_2".to_string());
-		let _ = game.join("test_3".to_string());
-		let mut game = game.start(game.host).unwrap();
-
-		game.current_turn = game.host;
+	fn leaving_removes_player() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let player2 = game.join("test_2".to_string()).unwrap();
 
-		let move_made = Move {
-			player_id: game.host,
-			..Default::default()
-		};
+		game.leave(player2).unwrap();
 
-		let _ = game.make_move(move_made);
-		assert!(game.in_progress_move.is_some());
+		assert_eq!(game.players.len(), 1);
+		assert!(game.leave(player2).is_err());
 	}
 
-	mod turn_logic {
-		use super::*;
+	#[test]
+	fn next_human_becomes_host_when_host_leaves() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let host = game.host;
+		game.add_bot(host, BotDifficulty::Easy).unwrap();
+		let player3 = game.join("test_3".to_string()).unwrap();
 
-		#[test]
-		fn returns_error_when_wrong_player_makes_turn() {
-			let mut game = Lobby::create("test_1".to_string());
-			let other_player = game.join("test_2".to_string()).unwrap();
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+		game.leave(host).unwrap();
 
-			println!("{game:?}");
+		assert_eq!(game.host, player3);
+	}
 
-			let move_made = Move {
-				player_id: if game.current_turn == game.host {other_player} else {game.host},
-				..Default::default()
-			};
+	#[test]
+	fn bots_get_removed_when_no_human_is_left() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		game.add_bot(game.host, BotDifficulty::Easy).unwrap();
 
-			let res = game.make_move(move_made);
+		game.leave(game.host).unwrap();
+		assert!(game.players.is_empty());
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotYourTurn.to_string());
-		}
+		let player = game.join("test_2".to_string()).unwrap();
+		assert_eq!(game.host, player);
+	}
 
-		#[test]
-		fn current_turn_gets_set_to_next_player_1() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
-	
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+	#[test]
+	fn only_host_can_kick() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let player2 = game.join("test_2".to_string()).unwrap();
+		let player3 = game.join("test_3".to_string()).unwrap();
 
-			game.current_turn = game.host;
-			game.get_extra_turns = 0;
+		let res = game.kick(player2, player3);
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+		let res = game.kick(game.host, game.host);
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
 
-			assert!(res.is_ok());
-			assert_eq!(game.current_turn, player2);
-		}
-	
-		#[test]
-		fn current_turn_gets_set_to_next_player_2() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
-	
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+		game.kick(game.host, player3).unwrap();
+		assert_eq!(game.players.len(), 2);
+	}
 
-			game.current_turn = game.host;
-			game.get_extra_turns = 0;
+	#[test]
+	fn host_can_be_transferred_to_humans_only() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let host = game.host;
+		let bot = game.add_bot(host, BotDifficulty::Easy).unwrap();
+		let player3 = game.join("test_3".to_string()).unwrap();
+
+		assert!(game.transfer_host(host, bot).is_err());
+		assert!(game.transfer_host(player3, player3).is_err());
+
+		game.transfer_host(host, player3).unwrap();
+		assert_eq!(game.host, player3);
+		assert!(game.start(host).is_err());
+	}
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+	#[test]
+	fn start_waits_for_everyone_to_be_ready_when_required() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let host = game.host;
+		let player2 = game.join("test_2".to_string()).unwrap();
+		game.add_bot(host, BotDifficulty::Easy).unwrap();
+		game.set_require_ready(host, true).unwrap();
+		game.set_ready(host, true).unwrap();
+
+		let res = game.start(host);
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEveryoneReady.to_string());
+
+		game.set_ready(player2, true).unwrap();
+		assert!(game.start(host).is_ok());
+	}
 
-			let move_made = Move {
-				player_id: player2,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("dijon".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+	#[test]
+	fn only_host_can_require_ready() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let player2 = game.join("test_2".to_string()).unwrap();
 
-			assert_eq!(game.current_turn, player3);
-		}
-	
-		#[test]
-		fn current_turn_gets_set_to_next_player_3() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
-	
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+		assert!(game.set_require_ready(player2, true).is_err());
+		assert!(!game.require_ready);
+	}
+}
 
-			game.current_turn = game.host;
-			game.get_extra_turns = 0;
+mod seed {
+	use super::*;
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+	fn start_seeded_game(seed: u64) -> InProgressGame {
+		let mut game = Lobby::create("test_1".to_string(), Some(seed));
+		let _ = game.join("test_2".to_string());
+		let _ = game.join("test_3".to_string());
+		return game.start(game.host).unwrap();
+	}
 
-			let move_made = Move {
-				player_id: player2,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("dijon".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
-			
-			let move_made = Move {
-				player_id: player3,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("luxembourg".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+	fn runner_name(game: &InProgressGame) -> String {
+		return game.players.iter().find(|x| x.id == game.runner).unwrap().display_name.clone();
+	}
 
-			assert_eq!(game.current_turn, game.host);
+	#[test]
+	fn same_seed_results_in_same_game() {
+		let game_1 = start_seeded_game(1234);
+		let game_2 = start_seeded_game(1234);
+
+		assert_eq!(runner_name(&game_1), runner_name(&game_2));
+		assert_eq!(game_1.destination, game_2.destination);
+		assert_eq!(game_1.timetable_card_stack, game_2.timetable_card_stack);
+		assert_eq!(game_1.event_card_stack, game_2.event_card_stack);
+		for (player_1, player_2) in game_1.players.iter().zip(game_2.players.iter()) {
+			assert_eq!(player_1.timetable_cards, player_2.timetable_cards);
 		}
+	}
 
-		#[test]
-		fn runner_gets_two_turns_at_start() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let _ = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn different_seeds_result_in_different_card_stacks() {
+		let game_1 = start_seeded_game(1234);
+		let game_2 = start_seeded_game(4321);
 
-			game.current_turn = game.host;
-			game.runner = game.host;
+		assert_ne!(game_1.timetable_card_stack, game_2.timetable_card_stack);
+	}
+
+	#[test]
+	fn seed_gets_generated_when_not_set() {
+		let game_1 = Lobby::create("test_1".to_string(), None);
+		let game_2 = Lobby::create("test_1".to_string(), None);
+
+		assert_ne!(game_1.seed, game_2.seed);
+	}
+
+	#[test]
+	fn same_seed_results_in_same_dice_rolls() {
+		let mut coins = Vec::new();
+		for _ in 0..2 {
+			let mut game = start_seeded_game(42);
 			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.current_location = if x.id == game.runner {Location::Paris} else {Location::Munich};
 				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
 				return x;
 			}).collect();
-			game.timetable_card_stack = vec![TimetableCard::LowSpeed; 10];
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-
-			let move_made = Move {
-				player_id: game.host,
+			let res = game.make_move(Move {
+				player_id: game.runner,
 				use_timetable_card: Some("low_speed".to_string()),
 				next_location: Some("le_havre".to_string()),
-				finish_move: true,
 				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+			}).unwrap();
 
-			assert_eq!(game.current_turn, player2);
+			coins.push(res.coins_received.unwrap());
 		}
 
-		#[test]
-		fn runner_gets_three_turns_at_start_with_4_players() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let _ = game.join("test_3".to_string()).unwrap();
-			let _ = game.join("test_4".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+		assert_eq!(coins[0], coins[1]);
+	}
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
-			game.timetable_card_stack = vec![TimetableCard::LowSpeed; 10];
+	#[test]
+	fn failed_move_doesnt_advance_rng() {
+		let mut game = start_seeded_game(42);
+		let rng_before = game.rng.clone();
+
+		let _ = game.make_move(Move {
+			player_id: game.runner,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("atlantis".to_string()),
+			..Default::default()
+		});
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+		assert_eq!(game.rng, rng_before);
+	}
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("le_havre".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+	#[test]
+	fn finished_game_reveals_seed() {
+		let game = start_seeded_game(42);
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("rennes".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+		let finished_game = FinishedGame::from_in_progress_game(&game, Team::Runner, WinCondition::GotToDestination);
 
-			assert_eq!(game.current_turn, player2);
-		}
+		assert_eq!(finished_game.seed, 42);
+	}
+}
 
-		#[test]
-		fn returns_error_when_finishing_move_without_moving() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string()).unwrap();
-			let _ = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+mod history {
+	use super::*;
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+	#[test]
+	fn accepted_move_gets_recorded() {
+		let (mut game, _, _) = TestGame::new().start();
 
-			let move_made = Move {
-				player_id: game.host,
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+		let res = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+
+		assert_eq!(game.history.len(), 1);
+		assert_eq!(game.history[0].turn, 1);
+		assert_eq!(game.history[0].player_id, game.host);
+		assert_eq!(game.history[0].move_made.next_location, Some("dijon".to_string()));
+		assert_eq!(game.history[0].move_result, res);
+		assert!(game.history[0].timestamp > 0);
+	}
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
-		}
+	#[test]
+	fn rejected_move_doesnt_get_recorded() {
+		let (mut game, player2, _) = TestGame::new().start();
+
+		let res = game.make_move(Move {
+			player_id: player2,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("stuttgart".to_string()),
+			..Default::default()
+		});
+
+		assert!(res.is_err());
+		assert!(game.history.is_empty());
 	}
 
-	mod next_location_handling {
-		use super::*;
+	#[test]
+	fn turn_counts_up_after_finished_move() {
+		let (mut game, player2, _) = TestGame::new().start();
 
-		#[test]
-		fn new_location_already_sent_gets_set_in_in_progress_move() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
-	
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+		let _ = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			..Default::default()
+		}).unwrap();
+		let _ = game.make_move(Move {
+			player_id: game.host,
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+		let _ = game.make_move(Move {
+			player_id: player2,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("stuttgart".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
 
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("paris".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				..Default::default()
-			};
-	
-			let _ = game.make_move(move_made);
+		assert_eq!(game.history.iter().map(|x| x.turn).collect::<Vec<usize>>(), vec![1, 1, 2]);
+		assert_eq!(game.turn, 3);
+	}
 
-			assert!(game.in_progress_move.unwrap().new_location_already_sent);
-		}
+	#[test]
+	fn coin_field_roll_gets_recorded() {
+		let (mut game, _, _) = TestGame::new().start();
 
-		#[test]
-		fn use_timetable_card_already_sent_gets_set_in_in_progress_move() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
-	
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+		let res = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("le_havre".to_string()),
+			..Default::default()
+		}).unwrap();
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				..Default::default()
-			};
-	
-			let _ = game.make_move(move_made);
+		assert_eq!(game.history[0].random_outcomes, vec![RandomOutcome::CoinFieldRoll(res.coins_received.unwrap())]);
+	}
 
-			assert!(game.in_progress_move.unwrap().use_timetable_card_already_sent);
-		}
+	#[test]
+	fn coin_field_roll_gets_logged() {
+		let (mut game, _, _) = TestGame::new().start();
 
-		#[test]
-		fn return_error_when_player_doesnt_have_right_timetable_card() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+		let res = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("le_havre".to_string()),
+			..Default::default()
+		}).unwrap();
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+		let roll = res.coins_received.unwrap() as u8;
+		assert_eq!(game.dice_result, Some(roll));
+		assert_eq!(game.dice_rolls, vec![DiceRoll { turn: 1, player_id: game.host, reason: RollReason::CoinField, roll }]);
+		assert_eq!(res.dice_rolls, game.dice_rolls);
+	}
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("joker".to_string()),
-				next_location: Some("paris".to_string()),
-				..Default::default()
-			};
+	#[test]
+	fn pizzazz_rolls_get_logged_for_every_player() {
+		let (mut game, player2, player3) = TestGame::new().start();
+		game.players[0].current_location = Location::Rennes;
+		game.coins_runner = 5;
+		game.event_card_stack = vec![EventCard::Pizzazz];
 
-			let res = game.make_move(move_made);
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::MissingTimetableCard.to_string());
-		}
+		game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("brest".to_string()),
+			buy_event_card: true,
+			..Default::default()
+		}).unwrap();
 
-		#[test]
-		fn returns_error_when_player_cant_get_to_next_location() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+		let rolled_by: Vec<PlayerId> = game.dice_rolls.iter().map(|x| x.player_id).collect();
+		assert_eq!(rolled_by, vec![game.host, player2, player3]);
+		assert!(game.dice_rolls.iter().all(|x| x.reason == RollReason::Pizzazz && (1..=6).contains(&x.roll)));
+		assert_eq!(game.coins_chasers, game.dice_rolls[1..].iter().map(|x| x.roll as usize).sum::<usize>());
+	}
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+	#[test]
+	fn rolls_of_the_runner_are_visible_to_chasers() {
+		let (mut game, player2, _) = TestGame::new().start();
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("berlin".to_string()),
-				..Default::default()
-			};
+		game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("le_havre".to_string()),
+			..Default::default()
+		}).unwrap();
 
-			let res = game.make_move(move_made);
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
-		}
+		assert!(game.history_for(player2)[0].move_made.next_location.is_none());
+		assert_eq!(game.history_for(player2)[0].move_result.dice_rolls.len(), 1);
+	}
 
-		#[test]
-		fn player_location_get_updated() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn runner_location_is_hidden_from_chasers() {
+		let (mut game, player2, _) = TestGame::new().start();
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+		let _ = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
-			
-			assert_eq!(game.players.first().unwrap().current_location, Location::Paris);
-		}
+		assert_eq!(game.history_for(game.host)[0].move_made.next_location, Some("dijon".to_string()));
+		assert_eq!(game.history_for(player2)[0].move_made.next_location, None);
+		assert!(game.history_for(player2)[0].move_result.timetable_cards_received.is_empty());
+		assert_eq!(game.history_for(player2)[0].move_made.use_timetable_card, Some("low_speed".to_string()));
+	}
 
-		#[test]
-		fn runner_path_gets_updated_for_runner() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn chaser_location_is_visible_to_everyone() {
+		let (mut game, player2, player3) = TestGame::new().start();
+		game.current_turn = player2;
+
+		let _ = game.make_move(Move {
+			player_id: player2,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("stuttgart".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+		assert_eq!(game.history_for(game.host)[0].move_made.next_location, Some("stuttgart".to_string()));
+		assert_eq!(game.history_for(player3)[0].move_made.next_location, Some("stuttgart".to_string()));
+	}
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+	#[test]
+	fn chaser_location_in_stealth_mode_is_hidden_from_runner() {
+		let (mut game, player2, player3) = TestGame::new().start();
+		game.current_turn = player2;
+		game.players[1].stealth_mode_active = true;
+		game.in_progress_move = Some(in_progress_game::InProgressMove { stealth_mode_enabled: true, ..Default::default() });
+
+		let _ = game.make_move(Move {
+			player_id: player2,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("stuttgart".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
 
-			assert_eq!(game.runner_path.first().unwrap(), &Location::Paris);
-		}
+		assert_eq!(game.history_for(game.host)[0].move_made.next_location, None);
+		assert_eq!(game.history_for(player3)[0].move_made.next_location, Some("stuttgart".to_string()));
+	}
 
-		#[test]
-		fn runner_path_doesnt_get_updated_for_chaser() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player1 = game.join("test_2".to_string()).unwrap();
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn finished_game_contains_complete_history() {
+		let (mut game, player2, _) = TestGame::new().start();
 
-			game.current_turn = player1;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+		let _ = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+
+		game.players[1].current_location = Location::Lyon;
+		let res = game.make_move(Move {
+			player_id: player2,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
 
-			let move_made = Move {
-				player_id: player1,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+		let finished_game = res.finished_game.unwrap();
+		assert_eq!(finished_game.history.len(), 2);
+		assert_eq!(finished_game.history[0].move_made.next_location, Some("dijon".to_string()));
+		assert!(finished_game.history[1].move_result.runner_caught);
+	}
+}
 
-			assert!(game.runner_path.is_empty());
-		}
+mod legal_moves {
+	use super::*;
+	use crate::game::legal_moves::LegalMove;
 
-		#[test]
-		fn last_used_timetable_card_gets_updated() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+	// At least one of every timetable card
+	fn host_cards() -> Vec<TimetableCard> {
+		return vec![TimetableCard::LowSpeed, TimetableCard::HighSpeed, TimetableCard::Plane, TimetableCard::Joker, TimetableCard::LowSpeed];
+	}
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
+	fn accepted_by_make_move(game: &InProgressGame, legal_move: &LegalMove) -> bool {
+		let mut game = game.clone();
+		return game.make_move(Move {
+			player_id: game.current_turn,
+			next_location: Some(legal_move.next_location.to_string()),
+			use_timetable_card: legal_move.timetable_card.as_ref().map(|x| x.to_string()),
+			..Default::default()
+		}).is_ok();
+	}
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+	#[test]
+	fn every_legal_move_gets_accepted() {
+		let (game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
 
-			assert_eq!(game.last_used_timetable_card.unwrap(), TimetableCard::LowSpeed);
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(!legal_moves.is_empty());
+		for legal_move in &legal_moves {
+			assert!(accepted_by_make_move(&game, legal_move), "{legal_move:?} got rejected");
 		}
+	}
 
-		#[test]
-		fn player_gets_new_card() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn contains_every_accepted_move() {
+		let (game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
-			game.timetable_card_stack = vec![TimetableCard::Joker];
+		let legal_moves = game.legal_moves(game.host);
 
-			let move_made = Move {
-				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+		for timetable_card in [TimetableCard::LowSpeed, TimetableCard::HighSpeed, TimetableCard::Plane, TimetableCard::Joker] {
+			for next_location in Location::get_iter() {
+				let candidate = LegalMove { timetable_card: Some(timetable_card.clone()), next_location };
+				assert_eq!(accepted_by_make_move(&game, &candidate), legal_moves.contains(&candidate), "{candidate:?}");
+			}
+		}
+	}
 
-			assert_eq!(game.players.iter().find(|x| x.id == game.runner).unwrap().timetable_cards.len(), 5);
-			assert_eq!(game.players.iter().find(|x| x.id == game.runner).unwrap().timetable_cards.iter().filter(|x| **x == TimetableCard::Joker).count(), 1);
+	#[test]
+	fn empty_when_not_your_turn() {
+		let (game, player2, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+
+		assert!(game.legal_moves(player2).is_empty());
+	}
+
+	#[test]
+	fn empty_after_already_moving() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+
+		game.make_move(Move { player_id: game.host, next_location: Some("brussels".to_string()), use_timetable_card: Some("low_speed".to_string()), ..Default::default() }).unwrap();
+
+		assert!(game.legal_moves(game.host).is_empty());
+	}
+
+	#[test]
+	fn space_occupied_by_chaser_isnt_legal() {
+		let (mut game, player2, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.players.iter_mut().find(|x| x.id == player2).unwrap().current_location = Location::Brussels;
+
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(!legal_moves.iter().any(|x| x.next_location == Location::Brussels));
+	}
+
+	#[test]
+	fn hunted_by_men_for_sport_only_allows_fastest_transport() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.players[0].must_use_fastest_transport_for_rounds = 1;
+		game.players[0].timetable_cards = vec![TimetableCard::LowSpeed, TimetableCard::Plane];
+
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(!legal_moves.is_empty());
+		assert!(legal_moves.iter().all(|x| x.timetable_card == Some(TimetableCard::Plane)));
+	}
+
+	#[test]
+	fn rat_mode_only_allows_slowest_transport() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.players[0].must_use_slowest_transport_for_rounds = 1;
+
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(!legal_moves.is_empty());
+		assert!(legal_moves.iter().all(|x| x.timetable_card == Some(TimetableCard::LowSpeed)));
+	}
+
+	#[test]
+	fn must_go_north_only_allows_north_connections() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.players[0].next_move_must_go_north_active = true;
+
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(!legal_moves.is_empty());
+		assert!(legal_moves.iter().all(|x| Location::Paris.get_north_connections().contains(&x.next_location)));
+	}
+
+	#[test]
+	fn luxembourg_is_germany_france_excludes_brussels() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.players[0].current_location = Location::Luxembourg;
+		game.players[0].luxembourg_is_germany_france_active = true;
+
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(!legal_moves.is_empty());
+		assert!(!legal_moves.iter().any(|x| x.next_location == Location::Brussels));
+	}
+
+	#[test]
+	fn beach_allows_coastal_locations_without_card() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.players[0].current_location = Location::Marseille;
+		game.players[0].lets_go_to_the_beach_active = true;
+
+		let legal_moves = game.legal_moves(game.host);
+
+		let without_card: Vec<&LegalMove> = legal_moves.iter().filter(|x| x.timetable_card.is_none()).collect();
+		assert!(!without_card.is_empty());
+		assert!(without_card.iter().all(|x| x.next_location.is_coastal()));
+		for legal_move in without_card {
+			assert!(accepted_by_make_move(&game, legal_move));
+		}
+	}
+
+	#[test]
+	fn winning_move_is_legal() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.destination = Location::Brussels;
+		game.coins_runner = 10;
+
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(legal_moves.iter().any(|x| x.next_location == Location::Brussels));
+	}
+
+	#[test]
+	fn slovenia_as_a_treat_allows_ljubljana_without_card() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Paris, host_cards()).start();
+		game.players[0].slovenia_as_a_treat_active = true;
+
+		let legal_moves = game.legal_moves(game.host);
+
+		assert!(legal_moves.contains(&LegalMove { timetable_card: None, next_location: Location::Ljubljana }));
+	}
+}
+
+mod bot {
+	use super::*;
+	use crate::game::bot::BotDifficulty;
+
+	fn all_bot_game(seed: u64, difficulty: BotDifficulty) -> Game {
+		let mut lobby = Lobby::create("test_1".to_string(), Some(seed));
+		lobby.add_bot(lobby.host, difficulty).unwrap();
+		lobby.add_bot(lobby.host, difficulty).unwrap();
+		lobby.add_bot(lobby.host, difficulty).unwrap();
+		let mut game = lobby.start(lobby.host).unwrap();
+		game.players[0].bot = Some(difficulty);
+
+		return Game::InProgress(game);
+	}
+
+	#[test]
+	fn add_bot_adds_bot_player() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+
+		lobby.add_bot(lobby.host, BotDifficulty::Easy).unwrap();
+		lobby.add_bot(lobby.host, BotDifficulty::Hard).unwrap();
+
+		assert_eq!(lobby.players.len(), 3);
+		assert_eq!(lobby.players[1].display_name, "Bot 1");
+		assert_eq!(lobby.players[1].bot, Some(BotDifficulty::Easy));
+		assert_eq!(lobby.players[2].display_name, "Bot 2");
+		assert_eq!(lobby.players[2].bot, Some(BotDifficulty::Hard));
+		assert!(lobby.players[0].bot.is_none());
+	}
+
+	#[test]
+	fn bots_skip_names_that_are_taken() {
+		let mut lobby = Lobby::create("bot 1".to_string(), None);
+
+		lobby.add_bot(lobby.host, BotDifficulty::Easy).unwrap();
+
+		assert_eq!(lobby.players[1].display_name, "Bot 2");
+	}
+
+	#[test]
+	fn only_host_can_add_bots() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let player2 = lobby.join("test_2".to_string()).unwrap();
+
+		assert!(lobby.add_bot(player2, BotDifficulty::Easy).is_err());
+		assert_eq!(lobby.players.len(), 2);
+	}
+
+	#[test]
+	fn bots_dont_join_full_lobby() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		for _ in 0..3 {
+			lobby.add_bot(lobby.host, BotDifficulty::Easy).unwrap();
+		}
+
+		assert!(lobby.add_bot(lobby.host, BotDifficulty::Easy).is_err());
+		assert_eq!(lobby.players.len(), 4);
+	}
+
+	#[test]
+	fn bots_play_until_its_a_humans_turn() {
+		let (mut game, _, _) = TestGame::new().bots(BotDifficulty::Hard).start();
+		game.make_move(Move { player_id: game.host, next_location: Some("brussels".to_string()), use_timetable_card: Some("low_speed".to_string()), finish_move: true, ..Default::default() }).unwrap();
+
+		let mut game = Game::InProgress(game);
+		game.play_bot_turns();
+
+		match game {
+			Game::InProgress(game) => {
+				assert_eq!(game.current_turn, game.host);
+				assert_eq!(game.history.iter().filter(|x| x.player_id != game.host).count(), 2);
+			},
+			Game::Lobby(_) => panic!("game is a lobby"),
+			Game::Finished(_) => (),
+		}
+	}
+
+	// Bots only stop before the game is over when the player whose turn it is has no cards left
+	// and the stack is empty, nobody can do anything then
+	fn assert_played_to_the_end(game: &Game, seed: u64) {
+		match game {
+			Game::InProgress(game) => {
+				let player = game.players.iter().find(|x| x.id == game.current_turn).unwrap();
+				assert!(player.timetable_cards.is_empty() && game.timetable_card_stack.is_empty(), "seed {seed} stopped early");
+			},
+			Game::Lobby(_) => panic!("game is a lobby"),
+			Game::Finished(_) => (),
+		}
+	}
+
+	#[test]
+	fn easy_bots_play_to_the_end() {
+		for seed in 0..5 {
+			let mut game = all_bot_game(seed, BotDifficulty::Easy);
+			game.play_bot_turns();
+
+			assert_played_to_the_end(&game, seed);
+		}
+	}
+
+	#[test]
+	fn hard_bots_play_to_the_end() {
+		for seed in 0..5 {
+			let mut game = all_bot_game(seed, BotDifficulty::Hard);
+			game.play_bot_turns();
+
+			assert_played_to_the_end(&game, seed);
 		}
+	}
+
+	#[test]
+	fn runner_takes_destination_with_enough_coins() {
+		let (mut game, player2, _) = TestGame::new().bots(BotDifficulty::Hard).player(1, Location::Munich, vec![TimetableCard::Joker; 5]).start();
+		game.runner = player2;
+		game.current_turn = player2;
+		game.destination = Location::Frankfurt;
+		game.coins_runner = 10;
+
+		let move_made = game.next_bot_move(BotDifficulty::Easy);
+
+		assert_eq!(move_made.next_location, Some("frankfurt".to_string()));
+	}
+
+	#[test]
+	fn hard_chaser_buys_runner_location() {
+		let (mut game, player2, _) = TestGame::new().bots(BotDifficulty::Hard).start();
+		game.current_turn = player2;
+		game.coins_chasers = 10;
+
+		let move_made = game.next_bot_move(BotDifficulty::Hard);
+
+		assert_eq!(move_made.buy_powerup, Some("learn_runner_location".to_string()));
+	}
+
+	#[test]
+	fn hard_chaser_catches_runner_at_known_location() {
+		let (mut game, player2, _) = TestGame::new().bots(BotDifficulty::Hard).player(1, Location::Munich, vec![TimetableCard::Joker; 5]).start();
+		game.current_turn = player2;
+		game.coins_chasers = 10;
+		game.players[1].current_location = Location::Frankfurt;
+
+		game.make_move(game.next_bot_move(BotDifficulty::Hard)).unwrap();
+		let move_made = game.next_bot_move(BotDifficulty::Hard);
+
+		assert_eq!(move_made.next_location, Some("paris".to_string()));
+	}
+}
+
+mod make_move {
+	use super::*;
+
+	#[test]
+	fn sets_in_progress_move_when_its_none() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let _ = game.join("test_2".to_string());
+		let _ = game.join("test_3".to_string());
+		let mut game = game.start(game.host).unwrap();
+
+		game.current_turn = game.host;
+
+		let move_made = Move {
+			player_id: game.host,
+			..Default::default()
+		};
+
+		let _ = game.make_move(move_made);
+		assert!(game.in_progress_move.is_some());
+	}
+
+	mod turn_logic {
+		use super::*;
 
 		#[test]
-		fn chaser_cant_move_to_location_of_other_chaser() {
-			let mut game = Lobby::create("test_1".to_string());
-			let chaser1 = game.join("test_2".to_string()).unwrap();
-			let chaser2 = game.join("test_3".to_string()).unwrap();
+		fn returns_error_when_wrong_player_makes_turn() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let other_player = game.join("test_2".to_string()).unwrap();
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker; 10];
-
-			game.players = vec![
-				Player { id: game.runner.clone(), display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: chaser1, display_name: "test_2".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: chaser2, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			println!("{game:?}");
 
-			game.current_turn = chaser2;
 			let move_made = Move {
-				player_id: chaser2,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
+				player_id: if game.current_turn == game.host {other_player} else {game.host},
 				..Default::default()
 			};
+
 			let res = game.make_move(move_made);
 
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotYourTurn.to_string());
 		}
 
 		#[test]
-		fn timetable_cards_received_gets_returned() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string());
-			let _ = game.join("test_3".to_string());
+		fn current_turn_gets_set_to_next_player_1() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
+	
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
 
 			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
-			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.get_extra_turns = 0;
 
 			let move_made = Move {
 				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
 				next_location: Some("paris".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
+			let res = game.make_move(move_made);
 
-			assert_eq!(res.timetable_cards_received, vec![TimetableCard::Joker]);
+			assert!(res.is_ok());
+			assert_eq!(game.current_turn, player2);
 		}
-	}
-
-	mod coin_fields {
-		use super::*;
-
+	
 		#[test]
-		fn runner_coins_get_updated_when_landing_on_coin_field() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn current_turn_gets_set_to_next_player_2() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
-
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-
+	
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
+			game.current_turn = game.host;
+			game.get_extra_turns = 0;
+
 			let move_made = Move {
 				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("le_havre".to_string()),
+				next_location: Some("paris".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
 			let _ = game.make_move(move_made);
 
-			assert!(game.coins_runner > 0);
-		}
-
-		#[test]
-		fn runner_coins_stay_zero_when_landing_on_non_coin_field() {
-			let mut game = Lobby::create("test_1".to_string());
-			let _ = game.join("test_2".to_string()).unwrap();
-			let _ = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
-
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.players = game.players.clone().into_iter().map(|mut x| {
-				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
-				return x;
-			}).collect();
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-
 			let move_made = Move {
-				player_id: game.host,
+				player_id: player2,
 				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("paris".to_string()),
+				next_location: Some("dijon".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
 			let _ = game.make_move(move_made);
 
-			assert_eq!(game.coins_runner, 0);
+			assert_eq!(game.current_turn, player3);
 		}
-
+	
 		#[test]
-		fn chaser_coins_get_updated_when_landing_on_coin_field() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn current_turn_gets_set_to_next_player_3() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
-
-			game.current_turn = player2;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-
+	
 			game.players = vec![
 				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
+			game.current_turn = game.host;
+			game.get_extra_turns = 0;
+
 			let move_made = Move {
-				player_id: player2,
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("le_havre".to_string()),
+				next_location: Some("paris".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
 			let _ = game.make_move(move_made);
 
-			assert!(game.coins_chasers > 0);
-		}
+			let move_made = Move {
+				player_id: player2,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("dijon".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+			
+			let move_made = Move {
+				player_id: player3,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("luxembourg".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
 
+			assert_eq!(game.current_turn, game.host);
+		}
 
 		#[test]
-		fn chaser_coins_stay_zero_when_landing_on_non_coin_field() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn runner_gets_two_turns_at_start() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let _ = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = game.host;
 			game.runner = game.host;
 			game.players = game.players.clone().into_iter().map(|mut x| {
 				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
 				return x;
 			}).collect();
-			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.timetable_card_stack = vec![TimetableCard::LowSpeed; 10];
 
 			let move_made = Move {
 				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
 				next_location: Some("paris".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
-
-			assert_eq!(game.coins_chasers, 0);
-		}
-
-		#[test]
-		fn coin_randomness_is_random_enough() {
-			let mut output: BTreeMap<i32, i32> = BTreeMap::new();
-			let n = 5_000;
-
-			for _ in 0..=n {
-				let mut game = Lobby::create("test_1".to_string());
-				let player2 = game.join("test_2".to_string()).unwrap();
-				let player3 = game.join("test_3".to_string()).unwrap();
-				let mut game = game.start(game.host).unwrap();
-	
-				game.current_turn = game.host;
-				game.runner = game.host;
-				game.timetable_card_stack = vec![TimetableCard::Joker];
-	
-				game.players = vec![
-					Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-					Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-					Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				];
-	
-				let move_made = Move {
-					player_id: game.host,
-					use_timetable_card: Some("low_speed".to_string()),
-					next_location: Some("le_havre".to_string()),
-					..Default::default()
-				};
-				let _ = game.make_move(move_made);
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
 
-				output.entry(game.coins_runner as i32).and_modify(|x| *x += 1).or_insert(1);
-			}
+			let move_made = Move {
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("le_havre".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
 
-			assert_eq!(output.len(), 6);
-			assert!(*output.get(&1).unwrap() > n / 7 && *output.get(&1).unwrap() < n / 5);
-			assert!(*output.get(&2).unwrap() > n / 7 && *output.get(&2).unwrap() < n / 5);
-			assert!(*output.get(&3).unwrap() > n / 7 && *output.get(&3).unwrap() < n / 5);
-			assert!(*output.get(&4).unwrap() > n / 7 && *output.get(&4).unwrap() < n / 5);
-			assert!(*output.get(&5).unwrap() > n / 7 && *output.get(&5).unwrap() < n / 5);
-			assert!(*output.get(&6).unwrap() > n / 7 && *output.get(&6).unwrap() < n / 5);
+			assert_eq!(game.current_turn, player2);
 		}
 
-
 		#[test]
-		fn number_of_received_coins_returned() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn runner_gets_three_turns_at_start_with_4_players() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+			let _ = game.join("test_3".to_string()).unwrap();
+			let _ = game.join("test_4".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
 			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
+			game.timetable_card_stack = vec![TimetableCard::LowSpeed; 10];
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			let move_made = Move {
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("paris".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
 
 			let move_made = Move {
 				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
 				next_location: Some("le_havre".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
 
-			assert!(res.coins_received.unwrap() > 0);
-			assert_eq!(res.coins_received.unwrap(), game.coins_runner);
-		}
-	}
+			let move_made = Move {
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("rennes".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
 
-	mod winning {
-		use super::*;
+			assert_eq!(game.current_turn, player2);
+		}
 
 		#[test]
-		fn runner_doesnt_win_when_getting_to_dest_without_10_coins() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn returns_error_when_finishing_move_without_moving() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string()).unwrap();
+			let _ = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
 			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
-			game.destination = Location::Madrid;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
 				player_id: game.host,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("madrid".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 
-			assert!(res.is_ok());
-			assert!(res.unwrap().finished_game.is_none());
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
 		}
+	}
+
+	mod next_location_handling {
+		use super::*;
 
 		#[test]
-		fn runner_wins_when_getting_to_dest_with_10_coins() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn new_location_already_sent_gets_set_in_in_progress_move() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
-
+	
 			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
-			game.destination = Location::Madrid;
-			game.coins_runner = 10;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
 				player_id: game.host,
+				next_location: Some("paris".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("madrid".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+	
+			let _ = game.make_move(move_made);
 
-			assert!(res.is_ok());
-			assert!(res.as_ref().unwrap().finished_game.is_some());
-			assert_eq!(res.as_ref().unwrap().clone().finished_game.unwrap().win_condition, WinCondition::GotToDestination);
-			assert_eq!(res.unwrap().finished_game.unwrap().winning_team, Team::Runner);
+			assert!(game.in_progress_move.unwrap().new_location_already_sent);
 		}
 
 		#[test]
-		fn chasers_win_when_catching_runner() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn use_timetable_card_already_sent_gets_set_in_in_progress_move() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
-
-			game.current_turn = player2;
+	
+			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
-			game.destination = Location::Madrid;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Madrid, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
-				player_id: player2,
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("zaragoza".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+	
+			let _ = game.make_move(move_made);
 
-			assert!(res.is_ok());
-			assert!(res.as_ref().unwrap().finished_game.is_some());
-			assert_eq!(res.as_ref().unwrap().clone().finished_game.unwrap().win_condition, WinCondition::RunnerCaught);
-			assert_eq!(res.unwrap().finished_game.unwrap().winning_team, Team::Chaser);
+			assert!(game.in_progress_move.unwrap().use_timetable_card_already_sent);
 		}
 
 		#[test]
-		fn catching_runner_returns_runner_caught_true() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn return_error_when_player_doesnt_have_right_timetable_card() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
-			game.destination = Location::Madrid;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Madrid, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
-				player_id: player2,
-				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("zaragoza".to_string()),
+				player_id: game.host,
+				use_timetable_card: Some("joker".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
 
-			assert!(res.unwrap().runner_caught);
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::MissingTimetableCard.to_string());
 		}
 
 		#[test]
-		fn chasers_win_when_card_stack_is_emptied() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn returns_error_when_player_cant_get_to_next_location() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
-			game.runner = game.runner;
-			game.timetable_card_stack = vec![];
-			game.destination = Location::Madrid;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Madrid, timetable_cards: vec![TimetableCard::LowSpeed], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed], ..Default::default() },
-			];
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
-				player_id: player2,
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				next_location: Some("albacete".to_string()),
+				next_location: Some("berlin".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
 
-			assert!(res.is_ok());
-			assert!(res.as_ref().unwrap().finished_game.is_some());
-			assert_eq!(res.as_ref().unwrap().clone().finished_game.unwrap().win_condition, WinCondition::TimetableCardsRanOut);
-			assert_eq!(res.unwrap().finished_game.unwrap().winning_team, Team::Chaser);
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
 		}
-	}
-
-	mod powerup {
-		use super::*;
 
 		#[test]
-		fn buying_runner_country_works() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn player_location_get_updated() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_powerup: Some("learn_runner_country".to_string()),
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
-
-			assert_eq!(res.power_up_status.runner_country.unwrap(), Country::Italy);
+			let _ = game.make_move(move_made);
+			
+			assert_eq!(game.players.first().unwrap().current_location, Location::Paris);
 		}
 
 		#[test]
-		fn buying_runner_country_doesnt_work_when_too_few_coins() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn runner_path_gets_updated_for_runner() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 4;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_powerup: Some("learn_runner_country".to_string()),
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let _ = game.make_move(move_made);
 
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
+			assert_eq!(game.runner_path.first().unwrap(), &Location::Paris);
 		}
 
 		#[test]
-		fn buying_runner_country_doesnt_work_when_too_few_coins_for_three_chasers() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let player4 = game.join("test_4".to_string()).unwrap();
+		fn runner_path_doesnt_get_updated_for_chaser() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player1 = game.join("test_2".to_string()).unwrap();
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = player1;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 9;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player4, display_name: "test_4".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_powerup: Some("learn_runner_country".to_string()),
+				player_id: player1,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let _ = game.make_move(move_made);
 
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
+			assert!(game.runner_path.is_empty());
 		}
 
 		#[test]
-		fn buying_runner_country_doesnt_do_anything_when_runner_buys_it() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn last_used_timetable_card_gets_updated() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
 			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_powerup: Some("learn_runner_country".to_string()),
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
+			let _ = game.make_move(move_made);
 
-			assert!(res.power_up_status.runner_country.is_none());
+			assert_eq!(game.last_used_timetable_card.unwrap(), TimetableCard::LowSpeed);
 		}
 
 		#[test]
-		fn buying_runner_location_works() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn player_gets_new_card() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = game.host;
 			game.runner = game.host;
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 10;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_powerup: Some("learn_runner_location".to_string()),
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
+			let _ = game.make_move(move_made);
 
-			assert_eq!(res.power_up_status.runner_location.unwrap(), Location::Padua);
+			assert_eq!(game.players.iter().find(|x| x.id == game.runner).unwrap().timetable_cards.len(), 5);
+			assert_eq!(game.players.iter().find(|x| x.id == game.runner).unwrap().timetable_cards.iter().filter(|x| **x == TimetableCard::Joker).count(), 1);
 		}
 
 		#[test]
-		fn buying_chaser_two_turns_works() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn chaser_cant_move_to_location_of_other_chaser() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let chaser1 = game.join("test_2".to_string()).unwrap();
+			let chaser2 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 100;
+			game.timetable_card_stack = vec![TimetableCard::Joker; 10];
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.runner.clone(), display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: chaser1, display_name: "test_2".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: chaser2, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
+			game.current_turn = chaser2;
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_powerup: Some("chaser_gets_two_turns".to_string()),
+				player_id: chaser2,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
-			assert!(res.power_up_status.get_another_turn);
-			assert_eq!(game.get_extra_turns, 1);
+			let res = game.make_move(move_made);
 
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
+		}
 
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("paris".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
+		#[test]
+		fn timetable_cards_received_gets_returned() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string());
+			let _ = game.join("test_3".to_string());
+			let mut game = game.start(game.host).unwrap();
 
-			assert_eq!(game.current_turn, player2);
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
+			game.timetable_card_stack = vec![TimetableCard::Joker];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("le_havre".to_string()),
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let res = game.make_move(move_made).unwrap();
 
-			assert_eq!(game.get_extra_turns, 0);
-			assert!(res.is_ok());
-			assert_eq!(game.current_turn, player3);
+			assert_eq!(res.timetable_cards_received, vec![TimetableCard::Joker]);
 		}
+	}
+
+	mod coin_fields {
+		use super::*;
 
 		#[test]
-		fn buying_runner_destination_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn runner_coins_get_updated_when_landing_on_coin_field() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
-			game.destination = Location::Dublin;
+			game.current_turn = game.host;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 100;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_powerup: Some("learn_runner_destination".to_string()),
+				player_id: game.host,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("le_havre".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
+			let _ = game.make_move(move_made);
 
-			assert_eq!(res.power_up_status.runner_destination.unwrap(), Location::Dublin);
+			assert!(game.coins_runner > 0);
 		}
-	}
-
-	mod buy_event_card {
-		use super::*;
 
 		#[test]
-		fn works() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+		fn runner_coins_stay_zero_when_landing_on_non_coin_field() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let _ = game.join("test_2".to_string()).unwrap();
+			let _ = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
 			game.current_turn = game.host;
 			game.runner = game.host;
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let _ = game.make_move(move_made);
 
-			assert!(res.is_ok());
-			assert!(res.as_ref().unwrap().event_card_received.is_some());
-			assert!(res.as_ref().unwrap().event_card_bought);
-			assert!(game.in_progress_move.unwrap().event_card_bought);
+			assert_eq!(game.coins_runner, 0);
 		}
 
 		#[test]
-		fn doesnt_work_when_new_location_didnt_get_sent() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn chaser_coins_get_updated_when_landing_on_coin_field() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				buy_event_card: true,
+				player_id: player2,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("le_havre".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let _ = game.make_move(move_made);
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardNoLocationSent.to_string());
+			assert!(game.coins_chasers > 0);
 		}
 
+
 		#[test]
-		fn doesnt_work_when_event_card_already_bought() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn chaser_coins_stay_zero_when_landing_on_non_coin_field() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
+			let _ = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
+			game.players = game.players.clone().into_iter().map(|mut x| {
+				x.timetable_cards = vec![TimetableCard::LowSpeed; 5];
+				return x;
+			}).collect();
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
-
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			
-			let move_made = Move {
-				player_id: game.current_turn,
-				buy_event_card: true,
+				next_location: Some("paris".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let _ = game.make_move(move_made);
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardAlreadyBought.to_string());
+			assert_eq!(game.coins_chasers, 0);
 		}
 
 		#[test]
-		fn doesnt_work_when_not_on_event_field() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn coin_randomness_is_random_enough() {
+			let mut output: BTreeMap<i32, i32> = BTreeMap::new();
+			let n = 5_000;
+
+			for _ in 0..=n {
+				let mut game = Lobby::create("test_1".to_string(), None);
+				let player2 = game.join("test_2".to_string()).unwrap();
+				let player3 = game.join("test_3".to_string()).unwrap();
+				let mut game = game.start(game.host).unwrap();
+	
+				game.current_turn = game.host;
+				game.runner = game.host;
+				game.timetable_card_stack = vec![TimetableCard::Joker];
+	
+				game.players = vec![
+					Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+					Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+					Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				];
+	
+				let move_made = Move {
+					player_id: game.host,
+					use_timetable_card: Some("low_speed".to_string()),
+					next_location: Some("le_havre".to_string()),
+					..Default::default()
+				};
+				let _ = game.make_move(move_made);
+
+				output.entry(game.coins_runner as i32).and_modify(|x| *x += 1).or_insert(1);
+			}
+
+			assert_eq!(output.len(), 6);
+			assert!(*output.get(&1).unwrap() > n / 7 && *output.get(&1).unwrap() < n / 5);
+			assert!(*output.get(&2).unwrap() > n / 7 && *output.get(&2).unwrap() < n / 5);
+			assert!(*output.get(&3).unwrap() > n / 7 && *output.get(&3).unwrap() < n / 5);
+			assert!(*output.get(&4).unwrap() > n / 7 && *output.get(&4).unwrap() < n / 5);
+			assert!(*output.get(&5).unwrap() > n / 7 && *output.get(&5).unwrap() < n / 5);
+			assert!(*output.get(&6).unwrap() > n / 7 && *output.get(&6).unwrap() < n / 5);
+		}
+
+
+		#[test]
+		fn number_of_received_coins_returned() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -1444,310 +1711,280 @@ mod make_move {
 			game.current_turn = game.host;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Paris, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("nantes".to_string()),
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				next_location: Some("le_havre".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let res = game.make_move(move_made).unwrap();
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotAnEventField.to_string());
+			assert!(res.coins_received.unwrap() > 0);
+			assert_eq!(res.coins_received.unwrap(), game.coins_runner);
 		}
+	}
+
+	mod winning {
+		use super::*;
 
 		#[test]
-		fn doesnt_work_if_runner_has_no_coins() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn runner_doesnt_win_when_getting_to_dest_without_10_coins() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
 			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 0;
+			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
+			game.destination = Location::Madrid;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				next_location: Some("madrid".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
+			assert!(res.is_ok());
+			assert!(res.unwrap().finished_game.is_none());
 		}
 
 		#[test]
-		fn doesnt_work_if_chaser_has_no_coins() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn runner_wins_when_getting_to_dest_with_10_coins() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = game.host;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 0;
-			game.coins_runner = 5;
+			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
+			game.destination = Location::Madrid;
+			game.coins_runner = 10;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
+				player_id: game.host,
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				next_location: Some("madrid".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
+			assert!(res.is_ok());
+			assert!(res.as_ref().unwrap().finished_game.is_some());
+			assert_eq!(res.as_ref().unwrap().clone().finished_game.unwrap().win_condition, WinCondition::GotToDestination);
+			assert_eq!(res.unwrap().finished_game.unwrap().winning_team, Team::Runner);
 		}
 
 		#[test]
-		fn event_card_stack_gets_smaller_by_one() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn chasers_win_when_catching_runner() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
+			game.destination = Location::Madrid;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Madrid, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			let mut problematic_cards_to_remove = 0;
-			if *game.event_card_stack.last().unwrap() == EventCard::GiveMeYourCards {
-				game.event_card_stack.pop();
-				problematic_cards_to_remove += 1;
-			}
-
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
+				player_id: player2,
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				next_location: Some("zaragoza".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
+			assert!(res.is_ok());
 
 			assert!(res.is_ok());
-			assert_eq!(game.event_card_stack.len(), 19 - problematic_cards_to_remove);
+			assert!(res.as_ref().unwrap().finished_game.is_some());
+			assert_eq!(res.as_ref().unwrap().clone().finished_game.unwrap().win_condition, WinCondition::RunnerCaught);
+			assert_eq!(res.unwrap().finished_game.unwrap().winning_team, Team::Chaser);
 		}
 
 		#[test]
-		fn returns_event_card_empty_error_when_its_empty() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn catching_runner_returns_runner_caught_true() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
-			game.event_card_stack = Vec::new();
+			game.timetable_card_stack = vec![TimetableCard::Joker; 5];
+			game.destination = Location::Madrid;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Madrid, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
+				player_id: player2,
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				next_location: Some("zaragoza".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
+			assert!(res.is_ok());
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardStackEmpty.to_string());
+			assert!(res.unwrap().runner_caught);
 		}
-	}
-
-	mod use_event_card {
-		use super::*;
 
 		#[test]
-		fn removes_correct_event_card_from_player() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn chasers_win_when_card_stack_is_emptied() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+			game.current_turn = player2;
+			game.runner = game.runner;
+			game.timetable_card_stack = vec![];
+			game.destination = Location::Madrid;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], event_cards: vec![EventCard::ItsAllInTheTrees, EventCard::SloveniaAsATreat], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Zaragoza, timetable_cards: vec![TimetableCard::LowSpeed], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Madrid, timetable_cards: vec![TimetableCard::LowSpeed], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed], ..Default::default() },
 			];
 
 			let move_made = Move {
-				player_id: game.current_turn,
-				use_event_card: Some("its_all_in_the_trees".to_string()),
+				player_id: player2,
+				use_timetable_card: Some("low_speed".to_string()),
+				next_location: Some("albacete".to_string()),
 				..Default::default()
 			};
-			let _ = game.make_move(move_made).unwrap();
+			let res = game.make_move(move_made);
 
-			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.len(), 1);
-			assert_eq!(*game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.first().unwrap(), EventCard::SloveniaAsATreat);
+			assert!(res.is_ok());
+			assert!(res.as_ref().unwrap().finished_game.is_some());
+			assert_eq!(res.as_ref().unwrap().clone().finished_game.unwrap().win_condition, WinCondition::TimetableCardsRanOut);
+			assert_eq!(res.unwrap().finished_game.unwrap().winning_team, Team::Chaser);
 		}
+	}
+
+	mod powerup {
+		use super::*;
 
 		#[test]
-		fn returns_error_when_player_doesnt_have_event_card() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn buying_runner_country_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
 			game.coins_chasers = 5;
-			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], event_cards: vec![EventCard::ItsAllInTheTrees, EventCard::SloveniaAsATreat], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
 			let move_made = Move {
 				player_id: game.current_turn,
-				use_event_card: Some("pizzazz".to_string()),
+				buy_powerup: Some("learn_runner_country".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
+			let res = game.make_move(move_made).unwrap();
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardNotOnYourHand.to_string());
+			assert_eq!(res.power_up_status.runner_country.unwrap(), Country::Italy);
 		}
 
 		#[test]
-		fn hunted_by_men_for_sport() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn buying_runner_country_doesnt_work_when_too_few_coins() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+			game.coins_chasers = 4;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::HuntedByMenForSport];
-
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made).unwrap();
-			
-			assert_eq!(res.event_card_received.unwrap(), EventCard::HuntedByMenForSport);
-			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().must_use_fastest_transport_for_rounds, 2);
-			
-			let move_made = Move {
-				player_id: game.current_turn,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-			
-			game.current_turn = game.host;
-			
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("nantes".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
+				buy_powerup: Some("learn_runner_country".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_fastest_transport_for_rounds, 1);
 
-			game.current_turn = game.host;
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
+		}
 
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("rennes".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+		#[test]
+		fn buying_runner_country_doesnt_work_when_too_few_coins_for_three_chasers() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let player4 = game.join("test_4".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YoureCurrentlyHuntedByMenForSport.to_string());
+			game.current_turn = player2;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 9;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player4, display_name: "test_4".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
 
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("paris".to_string()),
-				use_timetable_card: Some("high_speed".to_string()),
-				finish_move: true,
+				buy_powerup: Some("learn_runner_country".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 
-			assert!(res.is_ok());
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_fastest_transport_for_rounds, 0);
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
 		}
 
 		#[test]
-		fn give_me_your_cards_removes_card_from_player_with_cards() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn buying_runner_country_doesnt_do_anything_when_runner_buys_it() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -1756,163 +1993,140 @@ mod make_move {
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
 			game.coins_chasers = 5;
-			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], event_cards: vec![EventCard::RatMode, EventCard::Pizzazz] , ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::GiveMeYourCards];
-
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				buy_powerup: Some("learn_runner_country".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made).unwrap();
 
-			assert_ne!(res.event_card_received.unwrap(), EventCard::GiveMeYourCards);
-			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.len(), 1);
-			assert_eq!(game.players.iter().find(|x| x.id == player2).unwrap().event_cards.len(), 1);
+			assert!(res.power_up_status.runner_country.is_none());
 		}
 
 		#[test]
-		fn give_me_your_cards_gets_next_card_from_stack_when_no_player_has_any() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn buying_runner_location_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+			game.coins_chasers = 10;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::GiveMeYourCards];
-
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				buy_powerup: Some("learn_runner_location".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made).unwrap();
 
-			assert_eq!(res.event_card_received.unwrap(), EventCard::BingBong);
-			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.len(), 1);
+			assert_eq!(res.power_up_status.runner_location.unwrap(), Location::Padua);
 		}
 
 		#[test]
-		fn luxembourg_is_germany_france_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn buying_chaser_two_turns_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
+			game.current_turn = player2;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+			game.coins_chasers = 100;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Calais, timetable_cards: vec![TimetableCard::LowSpeed; 10], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::LuxembourgIsGermanyFrance];
-
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("ghent".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				buy_powerup: Some("chaser_gets_two_turns".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made).unwrap();
+			assert!(res.power_up_status.get_another_turn);
+			assert_eq!(game.get_extra_turns, 1);
 
-			assert_eq!(res.event_card_received.unwrap(), EventCard::LuxembourgIsGermanyFrance);
-			assert!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().luxembourg_is_germany_france_active);
-
-			let move_made = Move {
-				player_id: game.current_turn,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
 
-			game.current_turn = game.host;
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("brussels".to_string()),
+				next_location: Some("paris".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let _ = game.make_move(move_made).unwrap();
 
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("luxembourg".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
+			assert_eq!(game.current_turn, player2);
 
-			game.current_turn = game.host;
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("brussels".to_string()),
+				next_location: Some("le_havre".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouMustGoToGermanyOrFrance.to_string());
 
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("frankfurt".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+			assert_eq!(game.get_extra_turns, 0);
 			assert!(res.is_ok());
-			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().luxembourg_is_germany_france_active);
+			assert_eq!(game.current_turn, player3);
+		}
+
+		#[test]
+		fn buying_runner_destination_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = player2;
+			game.destination = Location::Dublin;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 100;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Padua, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
 
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("stuttgart".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
+				buy_powerup: Some("learn_runner_destination".to_string()),
 				..Default::default()
 			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			assert_ne!(game.current_turn, game.host);
+			let res = game.make_move(move_made).unwrap();
+
+			assert_eq!(res.power_up_status.runner_destination.unwrap(), Location::Dublin);
 		}
+	}
+
+	mod buy_event_card {
+		use super::*;
 
 		#[test]
-		fn lets_go_to_the_beach_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -1929,8 +2143,6 @@ mod make_move {
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::LetsGoToTheBeach];
-
 			let move_made = Move {
 				player_id: game.current_turn,
 				next_location: Some("brest".to_string()),
@@ -1938,33 +2150,17 @@ mod make_move {
 				buy_event_card: true,
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
+			let res = game.make_move(move_made);
 
-			assert_eq!(res.event_card_received.unwrap(), EventCard::LetsGoToTheBeach);
-			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
-
-			let move_made = Move {
-				player_id: game.current_turn,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("nice".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
 			assert!(res.is_ok());
-			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
+			assert!(res.as_ref().unwrap().event_card_received.is_some());
+			assert!(res.as_ref().unwrap().event_card_bought);
+			assert!(game.in_progress_move.unwrap().event_card_bought);
 		}
 
 		#[test]
-		fn lets_go_to_the_beach_doesnt_work_if_start_isnt_coastal() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn doesnt_work_when_new_location_didnt_get_sent() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -1976,47 +2172,25 @@ mod make_move {
 			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Calais, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::LetsGoToTheBeach];
-
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("ghent".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
 				buy_event_card: true,
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
-
-			assert_eq!(res.event_card_received.unwrap(), EventCard::LetsGoToTheBeach);
-			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
-
-			let move_made = Move {
-				player_id: game.current_turn,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("nice".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
 			let res = game.make_move(move_made);
+
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardNoLocationSent.to_string());
 		}
 
 		#[test]
-		fn lets_go_to_the_beach_doesnt_work_if_destination_isnt_coastal() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn doesnt_work_when_event_card_already_bought() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2033,8 +2207,6 @@ mod make_move {
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::LetsGoToTheBeach];
-
 			let move_made = Move {
 				player_id: game.current_turn,
 				next_location: Some("brest".to_string()),
@@ -2042,33 +2214,23 @@ mod make_move {
 				buy_event_card: true,
 				..Default::default()
 			};
-			let res = game.make_move(move_made).unwrap();
-
-			assert_eq!(res.event_card_received.unwrap(), EventCard::LetsGoToTheBeach);
-			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
-
-			let move_made = Move {
-				player_id: game.current_turn,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-
-			game.current_turn = game.host;
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			
 			let move_made = Move {
 				player_id: game.current_turn,
-				next_location: Some("berlin".to_string()),
-				finish_move: true,
+				buy_event_card: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
+
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardAlreadyBought.to_string());
 		}
 
 		#[test]
-		fn imagine_if_trains_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn doesnt_work_when_not_on_event_field() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2080,67 +2242,27 @@ mod make_move {
 			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::HighSpeed, TimetableCard::Plane], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::ImagineTrains];
-
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("brest".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-
-			let move_made = Move {
-				player_id: game.current_turn,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-
-			game.current_turn = game.host;
 			let move_made = Move {
 				player_id: game.current_turn,
 				next_location: Some("nantes".to_string()),
-				use_timetable_card: Some("high_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("bordeaux".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
+				buy_event_card: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
-			assert!(res.is_ok());
 
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.current_turn,
-				next_location: Some("toulouse".to_string()),
-				use_timetable_card: Some("plane".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotAnEventField.to_string());
 		}
-		
+
 		#[test]
-		fn consider_velocity_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn doesnt_work_if_runner_has_no_coins() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2149,7 +2271,7 @@ mod make_move {
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
 			game.coins_chasers = 5;
-			game.coins_runner = 5;
+			game.coins_runner = 0;
 
 			game.players = vec![
 				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
@@ -2157,8 +2279,6 @@ mod make_move {
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::ConsiderVelocity];
-
 			let move_made = Move {
 				player_id: game.current_turn,
 				next_location: Some("brest".to_string()),
@@ -2166,58 +2286,47 @@ mod make_move {
 				buy_event_card: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made).unwrap();
+			let res = game.make_move(move_made);
 
-			let move_made = Move {
-				player_id: game.host,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
+		}
 
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.host,
-				use_event_card: Some("consider_velocity".to_string()),
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("nantes".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+		#[test]
+		fn doesnt_work_if_chaser_has_no_coins() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
 
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("rennes".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+			game.current_turn = player2;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 0;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
 
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("le_havre".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("brest".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
+				buy_event_card: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
+
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotYourTurn.to_string());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
 		}
-		
+
 		#[test]
-		fn its_popsicle_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn event_card_stack_gets_smaller_by_one() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2234,7 +2343,11 @@ mod make_move {
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::ItsPopsicle];
+			let mut problematic_cards_to_remove = 0;
+			if *game.event_card_stack.last().unwrap() == EventCard::GiveMeYourCards {
+				game.event_card_stack.pop();
+				problematic_cards_to_remove += 1;
+			}
 
 			let move_made = Move {
 				player_id: game.current_turn,
@@ -2243,58 +2356,15 @@ mod make_move {
 				buy_event_card: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made).unwrap();
-
-			let move_made = Move {
-				player_id: game.host,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.host,
-				use_event_card: Some("its_popsicle".to_string()),
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("nantes".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
 			let res = game.make_move(move_made);
-			assert!(res.is_ok());
 
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("rennes".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
 			assert!(res.is_ok());
-
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("le_havre".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotYourTurn.to_string());
+			assert_eq!(game.event_card_stack.len(), 19 - problematic_cards_to_remove);
 		}
-		
+
 		#[test]
-		fn hydrate_or_diedrate_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn returns_event_card_empty_error_when_its_empty() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2304,6 +2374,7 @@ mod make_move {
 			game.timetable_card_stack = vec![TimetableCard::Joker];
 			game.coins_chasers = 5;
 			game.coins_runner = 5;
+			game.event_card_stack = Vec::new();
 
 			game.players = vec![
 				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
@@ -2311,8 +2382,6 @@ mod make_move {
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::HydrateOrDiedrate];
-
 			let move_made = Move {
 				player_id: game.current_turn,
 				next_location: Some("brest".to_string()),
@@ -2320,71 +2389,79 @@ mod make_move {
 				buy_event_card: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made).unwrap();
-
-			let move_made = Move {
-				player_id: game.host,
-				finish_move: true,
-				..Default::default()
-			};
 			let res = game.make_move(move_made);
-			assert!(res.is_ok());
+
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardStackEmpty.to_string());
 		}
-		
+	}
+
+	mod use_event_card {
+		use super::*;
+
 		#[test]
-		fn stealth_outfit_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn removes_correct_event_card_from_player() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = player2;
+			game.current_turn = game.host;
 			game.runner = game.host;
 			game.timetable_card_stack = vec![TimetableCard::Joker];
 			game.coins_chasers = 5;
 			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], event_cards: vec![EventCard::ItsAllInTheTrees, EventCard::SloveniaAsATreat], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::StealthOutfit];
-
 			let move_made = Move {
-				player_id: player2,
-				next_location: Some("brest".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
+				player_id: game.current_turn,
+				use_event_card: Some("its_all_in_the_trees".to_string()),
 				..Default::default()
 			};
 			let _ = game.make_move(move_made).unwrap();
 
+			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.len(), 1);
+			assert_eq!(*game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.first().unwrap(), EventCard::SloveniaAsATreat);
+		}
+
+		#[test]
+		fn returns_error_when_player_doesnt_have_event_card() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], event_cards: vec![EventCard::ItsAllInTheTrees, EventCard::SloveniaAsATreat], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
 			let move_made = Move {
-				player_id: player2,
-				finish_move: true,
+				player_id: game.current_turn,
+				use_event_card: Some("pizzazz".to_string()),
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			assert!(game.players.iter().find(|x| x.id == player2).unwrap().stealth_mode_active);
-			
-			game.current_turn = player2;
-			let move_made = Move {
-				player_id: player2,
-				next_location: Some("nantes".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: false,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made).unwrap();
-			assert!(!game.players.iter().find(|x| x.id == player2).unwrap().stealth_mode_active);
+
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::EventCardNotOnYourHand.to_string());
 		}
-		
+
 		#[test]
-		fn cardinal_directions_and_vibes_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn hunted_by_men_for_sport() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2396,59 +2473,110 @@ mod make_move {
 			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::HighSpeed], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::CardinalDirectionsAndVibes];
+			game.event_card_stack = vec![EventCard::HuntedByMenForSport];
 
 			let move_made = Move {
-				player_id: game.host,
+				player_id: game.current_turn,
 				next_location: Some("brest".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				buy_event_card: true,
 				..Default::default()
 			};
+			let res = game.make_move(move_made).unwrap();
+			
+			assert_eq!(res.event_card_received.unwrap(), EventCard::HuntedByMenForSport);
+			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().must_use_fastest_transport_for_rounds, 2);
+			
+			let move_made = Move {
+				player_id: game.current_turn,
+				finish_move: true,
+				..Default::default()
+			};
 			let _ = game.make_move(move_made).unwrap();
-
+			
+			game.current_turn = game.host;
+			
 			let move_made = Move {
-				player_id: game.host,
+				player_id: game.current_turn,
+				next_location: Some("nantes".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 			assert!(res.is_ok());
-			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().next_move_must_go_north_active);
-			
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_fastest_transport_for_rounds, 1);
+
 			game.current_turn = game.host;
+
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("nantes".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("rennes".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: false,
+				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
+
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouMustGoNorth.to_string());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YoureCurrentlyHuntedByMenForSport.to_string());
 
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("plymouth".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: false,
+				player_id: game.current_turn,
+				next_location: Some("paris".to_string()),
+				use_timetable_card: Some("high_speed".to_string()),
+				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
+
 			assert!(res.is_ok());
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_fastest_transport_for_rounds, 0);
+		}
 
-			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().next_move_must_go_north_active);
+		#[test]
+		fn give_me_your_cards_removes_card_from_player_with_cards() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], event_cards: vec![EventCard::RatMode, EventCard::Pizzazz] , ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::GiveMeYourCards];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made).unwrap();
+
+			assert_ne!(res.event_card_received.unwrap(), EventCard::GiveMeYourCards);
+			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.len(), 1);
+			assert_eq!(game.players.iter().find(|x| x.id == player2).unwrap().event_cards.len(), 1);
 		}
-		
+
 		#[test]
-		fn pizzazz_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn give_me_your_cards_gets_next_card_from_stack_when_no_player_has_any() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2465,24 +2593,25 @@ mod make_move {
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::Pizzazz];
+			//bing_bong would be played right away as a challenge
+			game.event_card_stack = vec![EventCard::ConsiderVelocity, EventCard::GiveMeYourCards];
 
 			let move_made = Move {
-				player_id: game.host,
+				player_id: game.current_turn,
 				next_location: Some("brest".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				buy_event_card: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made).unwrap();
-			assert!(res.coins_received.unwrap() > 0);
-			assert!(game.coins_chasers > 5);
-			assert!(game.coins_runner > 5);
+
+			assert_eq!(res.event_card_received.unwrap(), EventCard::ConsiderVelocity);
+			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().event_cards.len(), 1);
 		}
-		
+
 		#[test]
-		fn rat_mode_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn luxembourg_is_germany_france_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2494,69 +2623,91 @@ mod make_move {
 			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Calais, timetable_cards: vec![TimetableCard::LowSpeed; 10], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::RatMode];
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::LuxembourgIsGermanyFrance];
 
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("brest".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("ghent".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				buy_event_card: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
+			let res = game.make_move(move_made).unwrap();
+
+			assert_eq!(res.event_card_received.unwrap(), EventCard::LuxembourgIsGermanyFrance);
+			assert!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().luxembourg_is_germany_france_active);
 
 			let move_made = Move {
-				player_id: game.host,
+				player_id: game.current_turn,
 				finish_move: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 2);
+			let _ = game.make_move(move_made).unwrap();
 
 			game.current_turn = game.host;
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("nantes".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("brussels".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 1);
+			let _ = game.make_move(move_made).unwrap();
 
 			game.current_turn = game.host;
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("paris".to_string()),
-				use_timetable_card: Some("high_speed".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("luxembourg".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("brussels".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouAreCurrentlyInRatMode.to_string());
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 1);
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouMustGoToGermanyOrFrance.to_string());
 
 			game.current_turn = game.host;
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("la_rochelle".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("frankfurt".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 0);
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().luxembourg_is_germany_france_active);
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("stuttgart".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert_ne!(game.current_turn, game.host);
 		}
-		
+
 		#[test]
-		fn leave_country_immediately_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn lets_go_to_the_beach_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2573,53 +2724,218 @@ mod make_move {
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::LeaveCountryImmediately];
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::LetsGoToTheBeach];
 
 			let move_made = Move {
-				player_id: game.host,
+				player_id: game.current_turn,
 				next_location: Some("brest".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				buy_event_card: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
+			let res = game.make_move(move_made).unwrap();
+
+			assert_eq!(res.event_card_received.unwrap(), EventCard::LetsGoToTheBeach);
+			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
 
 			let move_made = Move {
-				player_id: game.host,
+				player_id: game.current_turn,
 				finish_move: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
-			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().leave_country_immediately_active);
+			let _ = game.make_move(move_made).unwrap();
 
 			game.current_turn = game.host;
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("nantes".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("nice".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
+		}
+
+		#[test]
+		fn lets_go_to_the_beach_doesnt_work_if_start_isnt_coastal() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Calais, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::LetsGoToTheBeach];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("ghent".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made).unwrap();
+
+			assert_eq!(res.event_card_received.unwrap(), EventCard::LetsGoToTheBeach);
+			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("nice".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouMustLeaveTheCountryImmediately.to_string());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
+		}
+
+		#[test]
+		fn lets_go_to_the_beach_doesnt_work_if_destination_isnt_coastal() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
 
 			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::LetsGoToTheBeach];
+
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("plymouth".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made).unwrap();
+
+			assert_eq!(res.event_card_received.unwrap(), EventCard::LetsGoToTheBeach);
+			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().lets_go_to_the_beach_active);
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("berlin".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
+		}
+
+		#[test]
+		fn imagine_if_trains_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::HighSpeed, TimetableCard::Plane], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::ImagineTrains];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("brest".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("nantes".to_string()),
+				use_timetable_card: Some("high_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 			assert!(res.is_ok());
-			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().leave_country_immediately_active);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("bordeaux".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("toulouse".to_string()),
+				use_timetable_card: Some("plane".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidNextLocation.to_string());
 		}
 		
 		#[test]
-		fn zug_faellt_aus_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn consider_velocity_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
@@ -2631,314 +2947,1838 @@ mod make_move {
 			game.coins_runner = 5;
 
 			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Cologne, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
 			];
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::ZugFaelltAus];
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::ConsiderVelocity];
 
 			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("bielefeld".to_string()),
+				player_id: game.current_turn,
+				next_location: Some("brest".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				buy_event_card: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
+			let _ = game.make_move(move_made).unwrap();
 
 			let move_made = Move {
 				player_id: game.host,
 				finish_move: true,
 				..Default::default()
 			};
-			let _ = game.make_move(move_made);
-			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().zug_faellt_aus_active);
+			let _ = game.make_move(move_made).unwrap();
 
 			game.current_turn = game.host;
 			let move_made = Move {
 				player_id: game.host,
-				next_location: Some("bremen".to_string()),
+				use_event_card: Some("consider_velocity".to_string()),
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("nantes".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 			assert!(res.is_ok());
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().current_location, Location::Bielefeld);
 
-			game.current_turn = game.host;
 			let move_made = Move {
 				player_id: game.host,
-				next_location: Some("bremen".to_string()),
+				next_location: Some("rennes".to_string()),
 				use_timetable_card: Some("low_speed".to_string()),
 				finish_move: true,
 				..Default::default()
 			};
 			let res = game.make_move(move_made);
 			assert!(res.is_ok());
-			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().zug_faellt_aus_active);
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().current_location, Location::Bremen);
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("le_havre".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotYourTurn.to_string());
 		}
 		
 		#[test]
-		fn its_all_in_the_trees_works() {
-			let mut game = Lobby::create("test_1".to_string());
+		fn its_popsicle_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
 			let player2 = game.join("test_2".to_string()).unwrap();
 			let player3 = game.join("test_3".to_string()).unwrap();
 			let mut game = game.start(game.host).unwrap();
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::ItsPopsicle];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				use_event_card: Some("its_popsicle".to_string()),
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("nantes".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("rennes".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("le_havre".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotYourTurn.to_string());
+		}
+		
+		#[test]
+		fn hydrate_or_diedrate_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::HydrateOrDiedrate];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+		}
+		
+		#[test]
+		fn stealth_outfit_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = player2;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::StealthOutfit];
+
+			let move_made = Move {
+				player_id: player2,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			let move_made = Move {
+				player_id: player2,
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert!(game.players.iter().find(|x| x.id == player2).unwrap().stealth_mode_active);
+			
+			game.current_turn = player2;
+			let move_made = Move {
+				player_id: player2,
+				next_location: Some("nantes".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: false,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+			assert!(!game.players.iter().find(|x| x.id == player2).unwrap().stealth_mode_active);
+		}
+		
+		#[test]
+		fn cardinal_directions_and_vibes_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::CardinalDirectionsAndVibes];
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made).unwrap();
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().next_move_must_go_north_active);
+			
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("nantes".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: false,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouMustGoNorth.to_string());
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("plymouth".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: false,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+
+			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().next_move_must_go_north_active);
+		}
+		
+		#[test]
+		fn pizzazz_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::Pizzazz];
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made).unwrap();
+			assert!(res.coins_received.unwrap() > 0);
+			assert!(game.coins_chasers > 5);
+			assert!(game.coins_runner > 5);
+		}
+		
+		#[test]
+		fn rat_mode_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::RatMode];
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 2);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("nantes".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 1);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("paris".to_string()),
+				use_timetable_card: Some("high_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouAreCurrentlyInRatMode.to_string());
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 1);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("la_rochelle".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().must_use_slowest_transport_for_rounds, 0);
+		}
+		
+		#[test]
+		fn leave_country_immediately_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::LeaveCountryImmediately];
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("brest".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().leave_country_immediately_active);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("nantes".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::YouMustLeaveTheCountryImmediately.to_string());
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("plymouth".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().leave_country_immediately_active);
+		}
+		
+		#[test]
+		fn zug_faellt_aus_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Cologne, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::ZugFaelltAus];
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("bielefeld".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().zug_faellt_aus_active);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("bremen".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().current_location, Location::Bielefeld);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("bremen".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().zug_faellt_aus_active);
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().current_location, Location::Bremen);
+		}
+		
+		#[test]
+		fn its_all_in_the_trees_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Cologne, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::ItsAllInTheTrees];
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("bielefeld".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("bremen".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert_ne!(game.current_turn, game.host);
+		}
+		
+		#[test]
+		fn slovenia_as_a_treat_works() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Cologne, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			game.event_card_stack = vec![EventCard::BingBong, EventCard::SloveniaAsATreat];
+
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("bielefeld".to_string()),
+				use_timetable_card: Some("low_speed".to_string()),
+				buy_event_card: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+
+			let move_made = Move {
+				player_id: game.host,
+				finish_move: true,
+				..Default::default()
+			};
+			let _ = game.make_move(move_made);
+			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().slovenia_as_a_treat_active);
+
+			game.current_turn = game.host;
+			let move_made = Move {
+				player_id: game.host,
+				next_location: Some("ljubljana".to_string()),
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+			assert!(res.is_ok());
+			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().current_location, Location::Ljubljana);
+			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().slovenia_as_a_treat_active);
+		}
+
+
+	}
+
+	mod throw_timetable_cards_away {
+		use super::*;
+
+		#[test]
+		fn returns_error_when_moves_possible() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nantes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string()],
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ValidMovePossible.to_string());
+		}
+
+		#[test]
+		fn returns_error_when_throwing_too_many_away() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string(), "high_speed".to_string()],
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ThrewTooManyTimetableCardsAway.to_string());
+		}
+
+		#[test]
+		fn returns_error_when_player_doesnt_have_card() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				throw_timetable_cards_away: vec!["plane".to_string(), "joker".to_string()],
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+
+			assert!(res.is_err());
+			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::MissingTimetableCard.to_string());
+		}
+
+		#[test]
+		fn doesnt_return_error_when_no_moves_possible() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::Joker];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string()],
+				finish_move: true,
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+
+			assert!(res.is_ok());
+		}
+
+		#[test]
+		fn actually_replaces_cards() {
+			let mut game = Lobby::create("test_1".to_string(), None);
+			let player2 = game.join("test_2".to_string()).unwrap();
+			let player3 = game.join("test_3".to_string()).unwrap();
+			let mut game = game.start(game.host).unwrap();
+
+			game.current_turn = game.host;
+			game.runner = game.host;
+			game.timetable_card_stack = vec![TimetableCard::LowSpeed; 5];
+			game.coins_chasers = 5;
+			game.coins_runner = 5;
+
+			game.players = vec![
+				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
+				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
+			];
+
+			let move_made = Move {
+				player_id: game.current_turn,
+				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string()],
+				..Default::default()
+			};
+			let res = game.make_move(move_made);
+
+			assert!(res.is_ok());
+			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().timetable_cards, vec![TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed]);
+		}
+
+
+	}
+
+
+}
+
+mod session {
+	use super::*;
+
+	#[test]
+	fn every_player_gets_their_own_session_token() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+
+		let host_token = lobby.players[0].session_token().unwrap();
+		let player2_token = lobby.players[1].session_token().unwrap();
+
+		assert_ne!(host_token, player2_token);
+		assert_eq!(host_token.len(), 64);
+		assert!(!host_token.contains(&lobby.players[0].id.simple().to_string()));
+	}
+
+	#[test]
+	fn bots_have_no_session_token() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+
+		lobby.add_bot(lobby.host, crate::game::bot::BotDifficulty::Easy).unwrap();
+
+		assert!(lobby.players[1].session_token().is_none());
+	}
+
+	#[test]
+	fn finished_game_views_dont_contain_session_tokens() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let player2 = lobby.join("test_2".to_string()).unwrap();
+		let _ = lobby.join("test_3".to_string());
+		let mut game = lobby.start(lobby.host).unwrap();
+		let session_tokens: Vec<String> = game.players.iter().map(|x| x.session_token().unwrap().to_string()).collect();
+
+		game.runner = game.host;
+		game.current_turn = game.host;
+		game.get_extra_turns = 0;
+		game.players[0].current_location = Location::Paris;
+		game.players[0].timetable_cards = vec![TimetableCard::LowSpeed; 5];
+		game.players[1].current_location = Location::Lyon;
+		game.players[1].timetable_cards = vec![TimetableCard::LowSpeed; 5];
+
+		let _ = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+		let res = game.make_move(Move {
+			player_id: player2,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+
+		let move_result = serde_json::to_string(&res).unwrap();
+		let current_state = rest_api::current_state(&Game::Finished(res.finished_game.unwrap()), None).unwrap().to_string();
+
+		for session_token in session_tokens {
+			assert!(!move_result.contains(&session_token));
+			assert!(!current_state.contains(&session_token));
+		}
+	}
+}
+
+mod current_state {
+	use super::*;
+
+	#[test]
+	fn chasers_are_keyed_by_player_id() {
+		let mut lobby = Lobby::create("test_1".to_string(), Some(1));
+		let player2 = lobby.join("test_2".to_string()).unwrap();
+		let player3 = lobby.join("test_3".to_string()).unwrap();
+		let mut game = lobby.start(lobby.host).unwrap();
+		game.runner = game.host;
+		game.current_turn = game.host;
+
+		let current_state = rest_api::current_state(&Game::InProgress(game.clone()), Some(player2)).unwrap();
+
+		assert_eq!(current_state["runner"], game.host.to_string());
+		assert_eq!(current_state["current_turn"], game.host.to_string());
+		assert_eq!(current_state["chaser_timetable_cards"].as_object().unwrap().len(), 2);
+		assert!(current_state["chaser_locations"].get(player3.to_string()).is_some());
+		assert_eq!(current_state["players"][1]["player_id"], player2.to_string());
+		assert_eq!(current_state["players"][1]["display_name"], "test_2");
+	}
+}
+
+mod spectator {
+	use super::*;
+
+	fn runner_moves_to_dijon(game: &mut InProgressGame) {
+		let _ = game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("dijon".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+	}
+
+	fn chaser_moves_to_stuttgart(game: &mut InProgressGame, player2: PlayerId) {
+		let _ = game.make_move(Move {
+			player_id: player2,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("stuttgart".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+	}
+
+	#[test]
+	fn spectator_invite_code_stays_the_same_after_start() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let invite_code = lobby.spectators.invite_code.clone();
+
+		let game = lobby.start(lobby.host).unwrap();
+
+		assert_eq!(game.spectators.invite_code, invite_code);
+		assert_ne!(invite_code, lobby.invite_code);
+	}
+
+	#[test]
+	fn only_host_can_set_broadcast_delay() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let player2 = lobby.join("test_2".to_string()).unwrap();
+
+		assert!(lobby.set_broadcast_delay(player2, Some(2)).is_err());
+		assert!(lobby.set_broadcast_delay(lobby.host, Some(2)).is_ok());
+		assert_eq!(lobby.spectators.broadcast_delay, Some(2));
+	}
+
+	#[test]
+	fn broadcast_delay_cant_be_too_long() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+
+		assert!(lobby.set_broadcast_delay(lobby.host, Some(crate::game::spectator::MAX_BROADCAST_DELAY + 1)).is_err());
+		assert_eq!(lobby.spectators.broadcast_delay, None);
+	}
+
+	#[test]
+	fn huge_broadcast_delay_keeps_runner_hidden() {
+		let (mut game, _, _) = TestGame::new().start();
+		//games saved before the limit existed can still have one
+		game.spectators.broadcast_delay = Some(usize::MAX);
+
+		assert_eq!(game.destination_for_spectators(), None);
+	}
+
+	#[test]
+	fn spectators_dont_see_runner_without_broadcast() {
+		let (mut game, _, _) = TestGame::new().start();
+
+		runner_moves_to_dijon(&mut game);
+
+		let state = rest_api::spectator_state(&Game::InProgress(game.clone()));
+		assert!(state.get("destination").is_none());
+		assert!(state["runner_current_location"].is_null());
+		assert!(state["broadcast"].is_null());
+		assert!(game.runner_path_for_spectators().is_none());
+		assert_eq!(game.history_for_spectators()[0].move_made.next_location, None);
+	}
+
+	#[test]
+	fn broadcast_reveals_runner_after_delay() {
+		let (mut game, player2, _) = TestGame::new().broadcast_delay(1).start();
+
+		runner_moves_to_dijon(&mut game);
+
+		assert_eq!(game.runner_path_for_spectators(), Some(Vec::new()));
+		assert_eq!(game.history_for_spectators()[0].move_made.next_location, None);
+		assert_eq!(game.destination_for_spectators(), Some(game.destination));
+
+		chaser_moves_to_stuttgart(&mut game, player2);
+
+		assert_eq!(game.runner_path_for_spectators(), Some(vec![Location::Dijon]));
+		assert_eq!(game.history_for_spectators()[0].move_made.next_location, Some("dijon".to_string()));
+
+		let state = rest_api::spectator_state(&Game::InProgress(game.clone()));
+		assert_eq!(state["broadcast"]["runner_path"][0], "dijon");
+	}
+
+	#[test]
+	fn broadcast_keeps_destination_hidden_during_delay() {
+		let (game, _, _) = TestGame::new().broadcast_delay(3).start();
+
+		assert_eq!(game.destination_for_spectators(), None);
+	}
+}
+
+mod settings {
+	use super::*;
+	use crate::game::settings::GameSettings;
+
+	#[test]
+	fn default_settings_are_valid() {
+		assert!(GameSettings::default().validate().is_ok());
+	}
+
+	#[test]
+	fn invalid_settings_get_rejected() {
+		let invalid = vec![
+			GameSettings { min_players: 1, ..Default::default() },
+			GameSettings { min_players: 4, max_players: 3, ..Default::default() },
+			GameSettings { max_players: 7, ..Default::default() },
+			GameSettings { starting_timetable_cards: 0, ..Default::default() },
+			GameSettings { destinations: vec![], ..Default::default() },
+			GameSettings { destinations: vec![Location::Vienna, Location::Vienna], ..Default::default() },
+			GameSettings { turn_time_limit: Some(5), ..Default::default() },
+			GameSettings { turn_time_limit: Some(crate::game::settings::MAX_TURN_TIME_LIMIT + 1), ..Default::default() },
+			GameSettings { timeouts_until_bot: 0, ..Default::default() },
+		];
+
+		for settings in invalid {
+			let res = settings.validate();
+			assert!(matches!(res, Err(crate::CustomError::InvalidSettings(_))), "{settings:?}");
+		}
+	}
+
+	#[test]
+	fn only_host_can_change_settings() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let player2 = game.join("test_2".to_string()).unwrap();
+
+		let res = game.set_settings(player2, GameSettings { coins_needed_at_destination: 0, ..Default::default() });
+
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
+		assert_eq!(game.settings, GameSettings::default());
+	}
+
+	#[test]
+	fn max_players_cant_be_below_players_in_lobby() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let _ = game.join("test_2".to_string());
+		let _ = game.join("test_3".to_string());
+		let _ = game.join("test_4".to_string());
+
+		assert!(game.set_settings(game.host, GameSettings { min_players: 3, max_players: 3, ..Default::default() }).is_err());
+	}
+
+	#[test]
+	fn player_limits_get_used() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		game.set_settings(game.host, GameSettings { min_players: 2, max_players: 2, ..Default::default() }).unwrap();
+		let _ = game.join("test_2".to_string());
+
+		let res = game.join("test_3".to_string());
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::LobbyFull.to_string());
+
+		let game = game.start(game.host).unwrap();
+		assert_eq!(game.get_extra_turns, 0);
+	}
+
+	#[test]
+	fn start_uses_destinations_and_starting_timetable_cards() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let _ = game.join("test_2".to_string());
+		let _ = game.join("test_3".to_string());
+		game.set_settings(game.host, GameSettings { destinations: vec![Location::Vienna], starting_timetable_cards: 8, ..Default::default() }).unwrap();
+
+		let game = game.start(game.host).unwrap();
+
+		assert_eq!(game.destination, Location::Vienna);
+		assert!(game.players.iter().all(|x| x.timetable_cards.len() == 8));
+	}
+
+	#[test]
+	fn runner_wins_with_coins_needed_at_destination() {
+		let (mut game, _, _) = TestGame::new().settings(GameSettings { coins_needed_at_destination: 3, ..Default::default() }).player(0, Location::Zaragoza, vec![TimetableCard::LowSpeed; 5]).start();
+		game.destination = Location::Madrid;
+		game.coins_runner = 3;
+
+		let move_made = Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("madrid".to_string()),
+			..Default::default()
+		};
+		let res = game.make_move(move_made).unwrap();
+
+		assert!(res.finished_game.is_some());
+	}
+
+	#[test]
+	fn powerup_prices_get_used() {
+		let mut settings = GameSettings::default();
+		settings.powerup_prices.learn_runner_country = 2;
+		let (mut game, player2, _) = TestGame::new().settings(settings).player(0, Location::Zaragoza, vec![TimetableCard::LowSpeed; 5]).start();
+		game.current_turn = player2;
+		game.coins_chasers = 2;
+
+		let move_made = Move {
+			player_id: player2,
+			buy_powerup: Some("learn_runner_country".to_string()),
+			..Default::default()
+		};
+		let res = game.make_move(move_made).unwrap();
+
+		assert_eq!(res.power_up_status.runner_country.unwrap(), Country::Spain);
+		assert_eq!(game.coins_chasers, 0);
+	}
+
+	#[test]
+	fn event_card_price_gets_used() {
+		let (mut game, _, _) = TestGame::new().settings(GameSettings { event_card_price: 3, ..Default::default() }).start();
+		game.players[0].current_location = Location::Rennes;
+		game.coins_runner = 2;
+
+		let move_made = Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("brest".to_string()),
+			buy_event_card: true,
+			..Default::default()
+		};
+		let res = game.make_move(move_made);
+
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::NotEnoughCoins.to_string());
+	}
+}
+
+mod challenge {
+	use super::*;
+	use crate::game::challenge::ChallengeOutcome;
+	use crate::game::in_progress_game::InProgressMove;
+	use crate::game::bot::BotDifficulty;
+
+	fn draw_challenge(event_card: EventCard) -> (InProgressGame, PlayerId, PlayerId, ChallengeId) {
+		let (mut game, player2, player3) = TestGame::new().player(0, Location::Rennes, vec![TimetableCard::LowSpeed; 5]).timetable_card_stack(vec![TimetableCard::Joker]).start();
+		game.coins_chasers = 5;
+		game.coins_runner = 5;
+		game.event_card_stack = vec![event_card];
+
+		let move_made = Move {
+			player_id: game.host,
+			next_location: Some("brest".to_string()),
+			use_timetable_card: Some("low_speed".to_string()),
+			buy_event_card: true,
+			..Default::default()
+		};
+		let res = game.make_move(move_made).unwrap();
+
+		return (game, player2, player3, res.challenge.unwrap());
+	}
+
+	#[test]
+	fn drawing_flavor_card_starts_challenge() {
+		for event_card in [EventCard::HydrateOrDiedrate, EventCard::SnackZone, EventCard::BonjourToEveryone, EventCard::NoTalk, EventCard::BingBong] {
+			let (game, _, _, challenge_id) = draw_challenge(event_card.clone());
+
+			assert_eq!(game.challenges.len(), 1);
+			assert_eq!(game.challenges[0].id, challenge_id);
+			assert_eq!(game.challenges[0].event_card, event_card);
+			assert_eq!(game.challenges[0].outcome, None);
+			assert!(game.players[0].event_cards.is_empty());
+		}
+	}
+
+	#[test]
+	fn challenge_drawn_for_give_me_your_cards_starts_challenge() {
+		let (mut game, _, _) = TestGame::new().player(0, Location::Rennes, vec![TimetableCard::LowSpeed; 5]).timetable_card_stack(vec![TimetableCard::Joker]).start();
+		game.coins_chasers = 5;
+		game.coins_runner = 5;
+		//nobody has event cards to take, so the next card of the stack gets drawn instead
+		game.event_card_stack = vec![EventCard::NoTalk, EventCard::GiveMeYourCards];
+
+		let move_made = Move {
+			player_id: game.host,
+			next_location: Some("brest".to_string()),
+			use_timetable_card: Some("low_speed".to_string()),
+			buy_event_card: true,
+			..Default::default()
+		};
+		let res = game.make_move(move_made).unwrap();
+
+		assert_eq!(res.challenge, Some(game.challenges[0].id));
+		assert_eq!(game.challenges[0].event_card, EventCard::NoTalk);
+		assert!(game.players[0].muted);
+		assert!(game.players[0].event_cards.is_empty());
+	}
+
+	#[test]
+	fn other_cards_dont_start_challenges() {
+		let mut game = Lobby::create("test_1".to_string(), None);
+		let _ = game.join("test_2".to_string());
+		let _ = game.join("test_3".to_string());
+		let mut game = game.start(game.host).unwrap();
+		game.current_turn = game.host;
+		game.coins_runner = 5;
+		game.coins_chasers = 5;
+		game.players[0].current_location = Location::Rennes;
+		game.players[0].timetable_cards = vec![TimetableCard::LowSpeed; 5];
+		game.event_card_stack = vec![EventCard::RatMode];
+
+		let move_made = Move {
+			player_id: game.host,
+			next_location: Some("brest".to_string()),
+			use_timetable_card: Some("low_speed".to_string()),
+			buy_event_card: true,
+			..Default::default()
+		};
+		let res = game.make_move(move_made).unwrap();
+
+		assert!(res.challenge.is_none());
+		assert!(game.challenges.is_empty());
+	}
+
+	#[test]
+	fn confirmed_challenge_rewards_team() {
+		let (mut game, player2, player3, challenge_id) = draw_challenge(EventCard::SnackZone);
+
+		assert_eq!(game.vote_on_challenge(player2, challenge_id, true).unwrap(), None);
+		assert_eq!(game.vote_on_challenge(player3, challenge_id, true).unwrap(), Some(ChallengeOutcome::Completed));
+
+		assert_eq!(game.coins_runner, 7);
+		assert_eq!(game.coins_chasers, 5);
+	}
+
+	#[test]
+	fn rejected_challenge_costs_coins() {
+		let (mut game, player2, _, challenge_id) = draw_challenge(EventCard::HydrateOrDiedrate);
+
+		assert_eq!(game.vote_on_challenge(player2, challenge_id, false).unwrap(), Some(ChallengeOutcome::Failed));
+
+		assert_eq!(game.coins_runner, 4);
+	}
+
+	#[test]
+	fn votes_cant_be_cast_twice_after_resolving() {
+		let (mut game, player2, player3, challenge_id) = draw_challenge(EventCard::BonjourToEveryone);
+		game.vote_on_challenge(player2, challenge_id, false).unwrap();
+
+		let res = game.vote_on_challenge(player3, challenge_id, true);
+
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidGameState.to_string());
+	}
+
+	#[test]
+	fn player_cant_vote_on_own_challenge() {
+		let (mut game, _, _, challenge_id) = draw_challenge(EventCard::BingBong);
+
+		let res = game.vote_on_challenge(game.host, challenge_id, true);
+
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
+	}
+
+	#[test]
+	fn unknown_challenge_isnt_found() {
+		let (mut game, player2, _, _) = draw_challenge(EventCard::BingBong);
+
+		let res = game.vote_on_challenge(player2, ChallengeId::new_v4(), true);
+
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ChallengeNotFound.to_string());
+	}
+
+	#[test]
+	fn no_talk_mutes_until_next_turn() {
+		let (mut game, player2, player3, _) = draw_challenge(EventCard::NoTalk);
+		assert!(game.players[0].muted);
+
+		for player_id in [game.host, player2, player3] {
+			assert!(game.players[0].muted);
+			assert_eq!(game.current_turn, player_id);
+			game.in_progress_move = Some(InProgressMove { new_location_already_sent: true, ..Default::default() });
+			let move_made = Move {
+				player_id,
+				finish_move: true,
+				..Default::default()
+			};
+			game.make_move(move_made).unwrap();
+		}
+
+		assert_eq!(game.current_turn, game.host);
+		assert!(!game.players[0].muted);
+	}
+
+	#[test]
+	fn failed_no_talk_skips_next_turn() {
+		let (mut game, player2, player3, challenge_id) = draw_challenge(EventCard::NoTalk);
+		game.vote_on_challenge(player2, challenge_id, false).unwrap();
+		game.in_progress_move = None;
+		game.players[0].skip_turns = 1;
+		game.current_turn = player3;
+		game.players[2].current_location = Location::Nancy;
+
+		let move_made = Move {
+			player_id: player3,
+			next_location: Some(Location::Nancy.get_low_speed_connections()[0].to_string()),
+			use_timetable_card: Some("low_speed".to_string()),
+			finish_move: true,
+			..Default::default()
+		};
+		game.make_move(move_made).unwrap();
+
+		assert_eq!(game.current_turn, player2);
+		assert_eq!(game.players[0].skip_turns, 0);
+	}
+
+	#[test]
+	fn bot_challenges_count_right_away() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let bot = lobby.add_bot(lobby.host, BotDifficulty::Easy).unwrap();
+		let _ = lobby.join("test_3".to_string());
+		let mut game = lobby.start(lobby.host).unwrap();
+		game.runner = game.host;
+
+		game.start_challenge(bot, EventCard::SnackZone);
+
+		assert_eq!(game.challenges[0].outcome, Some(ChallengeOutcome::Completed));
+		assert_eq!(game.coins_chasers, 2);
+	}
+}
+
+mod turn_timer {
+	use super::*;
+	use crate::game::settings::GameSettings;
+
+	fn in_progress(game: &Game) -> &InProgressGame {
+		return match game {
+			Game::InProgress(game) => game,
+			_ => panic!("game isn't in progress"),
+		};
+	}
+
+	#[test]
+	fn nothing_happens_without_time_limit() {
+		let (game, _, _) = TestGame::new().start();
+		let mut game = Game::InProgress(game);
+
+		assert!(!game.time_out_turn(u64::MAX));
+		assert_eq!(in_progress(&game).turn_deadline(), None);
+	}
+
+	#[test]
+	fn nothing_happens_before_deadline() {
+		let (game, _, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), ..Default::default() }).start();
+		let mut game = Game::InProgress(game);
+
+		assert_eq!(in_progress(&game).turn_deadline(), Some(1_030_000));
+		assert!(!game.time_out_turn(1_029_999));
+		assert!(in_progress(&game).history.is_empty());
+	}
+
+	#[test]
+	fn huge_time_limit_doesnt_overflow() {
+		let (mut game, _, _) = TestGame::new().start();
+		//games saved before the limit had a maximum can still have one
+		game.settings.turn_time_limit = Some(u64::MAX / 1000);
+		game.turn_started_at = u64::MAX - 5;
+		assert_eq!(game.turn_deadline(), Some(u64::MAX));
+
+		game.settings.turn_time_limit = Some(u64::MAX);
+		assert_eq!(game.turn_deadline(), None);
+		assert!(!Game::InProgress(game).time_out_turn(u64::MAX - 1));
+	}
+
+	#[test]
+	fn timed_out_turn_gets_finished_with_legal_move() {
+		let (game, player2, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), ..Default::default() }).start();
+		let host = game.host;
+		let mut game = Game::InProgress(game);
+
+		assert!(game.time_out_turn(1_030_000));
+
+		let game = in_progress(&game);
+		assert_eq!(game.current_turn, player2);
+		assert_ne!(game.players[0].current_location, Location::Paris);
+		assert_eq!(game.players[0].timeouts, 1);
+		assert_eq!(game.history[0].player_id, host);
+		assert!(game.turn_started_at > 1_030_000);
+	}
+
+	#[test]
+	fn timed_out_turn_throws_cards_away_without_legal_move() {
+		let (game, player2, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), ..Default::default() }).player(0, Location::Nancy, vec![TimetableCard::Plane; 5]).start();
+		let mut game = Game::InProgress(game);
+
+		assert!(game.time_out_turn(1_030_000));
+
+		let game = in_progress(&game);
+		assert_eq!(game.current_turn, player2);
+		assert_eq!(game.players[0].current_location, Location::Nancy);
+		assert_eq!(game.history[0].move_made.throw_timetable_cards_away.len(), 2);
+	}
+
+	#[test]
+	fn bot_takes_over_after_repeated_timeouts() {
+		let (mut game, _, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), timeouts_until_bot: 2, ..Default::default() }).start();
+		game.players[0].timeouts = 1;
+		let mut game = Game::InProgress(game);
+
+		assert!(game.time_out_turn(1_030_000));
+
+		let game = in_progress(&game);
+		assert!(game.players[0].bot.is_some());
+		assert_eq!(game.players[0].timeouts, 2);
+	}
+
+	#[test]
+	fn stuck_bot_turn_gets_ended_without_time_limit() {
+		let (mut game, player2, _) = TestGame::new().start();
+		game.players[0].bot = Some(BotDifficulty::Easy);
+		let mut game = Game::InProgress(game);
+
+		assert!(!game.time_out_turn(1_009_999));
+		assert!(game.time_out_turn(1_010_000));
+
+		let game = in_progress(&game);
+		assert_eq!(game.current_turn, player2);
+		assert_eq!(game.players[0].timeouts, 0);
+		assert_ne!(game.players[0].current_location, Location::Paris);
+	}
+
+	#[test]
+	fn finishing_own_move_resets_timeouts() {
+		let (mut game, _, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), ..Default::default() }).start();
+		game.players[0].timeouts = 1;
+
+		game.make_move(Move {
+			player_id: game.host,
+			use_timetable_card: Some("low_speed".to_string()),
+			next_location: Some("le_havre".to_string()),
+			finish_move: true,
+			..Default::default()
+		}).unwrap();
+
+		assert_eq!(game.players[0].timeouts, 0);
+	}
+
+	#[test]
+	fn replaced_player_can_take_back() {
+		let (mut game, player2, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), ..Default::default() }).start();
+
+		assert_eq!(game.take_back(player2).err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
+
+		game.players[1].bot = Some(crate::game::bot::BotDifficulty::Easy);
+		game.players[1].timeouts = 2;
+		game.take_back(player2).unwrap();
+
+		assert!(game.players[1].bot.is_none());
+		assert_eq!(game.players[1].timeouts, 0);
+	}
+
+	#[test]
+	fn panicking_game_doesnt_stop_the_others() {
+		use crate::game_store::{GameStore, InMemoryGameStore};
+
+		let store = InMemoryGameStore::new();
+		let (timed_out, _, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), ..Default::default() }).start();
+		let (mut broken, _, _) = TestGame::new().settings(GameSettings { turn_time_limit: Some(30), ..Default::default() }).start();
+		//nobody has the turn, so finishing it panics
+		broken.current_turn = PlayerId::nil();
+		let (timed_out_id, broken_id) = (timed_out.id, broken.id);
+		store.insert(Game::InProgress(timed_out)).unwrap();
+		store.insert(Game::InProgress(broken)).unwrap();
+
+		assert_eq!(crate::game::turn_timer::time_out_turns(&store, 1_030_000), vec![timed_out_id]);
+		assert!(!in_progress(&store.get(&timed_out_id).unwrap().blocking_lock()).history.is_empty());
+		assert!(store.get(&broken_id).is_some());
+	}
+}
+
+mod rematch {
+	use super::*;
+	use crate::game::settings::GameSettings;
+
+	fn finish_game() -> (FinishedGame, PlayerId, PlayerId) {
+		let (mut game, player2, player3) = TestGame::new().settings(GameSettings { coins_needed_at_destination: 7, ..Default::default() }).start();
+		game.runner = player2;
+
+		return (FinishedGame::from_in_progress_game(&game, Team::Runner, WinCondition::GotToDestination), player2, player3);
+	}
+
+	#[test]
+	fn keeps_players_host_and_settings() {
+		let (mut finished_game, player2, _) = finish_game();
+
+		let lobby = finished_game.rematch(player2, false).unwrap();
+
+		assert_ne!(lobby.id, finished_game.id);
+		assert_eq!(lobby.host, finished_game.host);
+		assert_eq!(lobby.settings, finished_game.settings);
+		assert_eq!(lobby.players.iter().map(|x| (x.id, x.display_name.clone())).collect::<Vec<_>>(), finished_game.players.iter().map(|x| (x.id, x.display_name.clone())).collect::<Vec<_>>());
+		assert!(lobby.players.iter().all(|x| x.timetable_cards.is_empty() && !x.ready));
+		assert_eq!(lobby.next_runner, None);
+	}
+
+	#[test]
+	fn players_get_new_session_tokens() {
+		let (mut finished_game, player2, _) = finish_game();
+
+		let lobby = finished_game.rematch(player2, false).unwrap();
+		let rematch = finished_game.rematch.clone().unwrap();
+
+		assert_eq!(rematch.game_id, lobby.id);
+		assert_eq!(rematch.invite_code, lobby.invite_code);
+		for player in &lobby.players {
+			let old_token = finished_game.players.iter().find(|x| x.id == player.id).unwrap().session_token().unwrap();
+			assert_ne!(player.session_token().unwrap(), old_token);
+			assert_eq!(rematch.session_tokens.get(&player.id).map(|x| x.as_str()), player.session_token());
+		}
+	}
+
+	#[test]
+	fn only_players_can_ask_for_a_rematch() {
+		let (mut finished_game, _, _) = finish_game();
+
+		let res = finished_game.rematch(PlayerId::new_v4(), false);
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Cologne, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+		assert!(res.is_err());
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::UnknownPlayer.to_string());
+		assert!(finished_game.rematch.is_none());
+	}
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::ItsAllInTheTrees];
+	#[test]
+	fn only_one_rematch_per_game() {
+		let (mut finished_game, player2, player3) = finish_game();
 
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("bielefeld".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+		finished_game.rematch(player2, false).unwrap();
+		let res = finished_game.rematch(player3, false);
 
-			let move_made = Move {
-				player_id: game.host,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+		assert!(res.is_err());
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidGameState.to_string());
+	}
 
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("bremen".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			assert_ne!(game.current_turn, game.host);
-		}
-		
-		#[test]
-		fn slovenia_as_a_treat_works() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn rotate_runner_makes_next_player_runner() {
+		let (mut finished_game, player2, player3) = finish_game();
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+		let mut lobby = finished_game.rematch(player2, true).unwrap();
+		assert_eq!(lobby.next_runner, Some(player3));
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Cologne, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+		let game = lobby.start(lobby.host).unwrap();
+		assert_eq!(game.runner, player3);
+		assert_eq!(game.current_turn, player3);
+	}
 
-			game.event_card_stack = vec![EventCard::BingBong, EventCard::SloveniaAsATreat];
+	#[test]
+	fn rotate_runner_wraps_around() {
+		let (mut finished_game, _, player3) = finish_game();
+		finished_game.runner = player3;
 
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("bielefeld".to_string()),
-				use_timetable_card: Some("low_speed".to_string()),
-				buy_event_card: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
+		let lobby = finished_game.rematch(player3, true).unwrap();
 
-			let move_made = Move {
-				player_id: game.host,
-				finish_move: true,
-				..Default::default()
-			};
-			let _ = game.make_move(move_made);
-			assert!(game.players.iter().find(|x| x.id == game.host).unwrap().slovenia_as_a_treat_active);
+		assert_eq!(lobby.next_runner, Some(finished_game.host));
+	}
 
-			game.current_turn = game.host;
-			let move_made = Move {
-				player_id: game.host,
-				next_location: Some("ljubljana".to_string()),
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
-			assert!(res.is_ok());
-			assert_eq!(game.players.iter().find(|x| x.id == game.host).unwrap().current_location, Location::Ljubljana);
-			assert!(!game.players.iter().find(|x| x.id == game.host).unwrap().slovenia_as_a_treat_active);
-		}
+	#[test]
+	fn next_runner_who_left_gets_replaced() {
+		let (mut finished_game, player2, player3) = finish_game();
 
+		let mut lobby = finished_game.rematch(player2, true).unwrap();
+		lobby.leave(player3).unwrap();
+		let _ = lobby.join("test_4".to_string());
+		let game = lobby.start(lobby.host).unwrap();
 
+		assert_ne!(game.runner, player3);
+		assert!(game.players.iter().any(|x| x.id == game.runner));
 	}
 
-	mod throw_timetable_cards_away {
-		use super::*;
+	#[test]
+	fn bots_stay_bots_and_replaced_players_play_again() {
+		let (mut finished_game, player2, player3) = finish_game();
+		finished_game.players.iter_mut().find(|x| x.id == player2).unwrap().bot = Some(BotDifficulty::Easy);
+		let bot = finished_game.players.iter_mut().find(|x| x.id == player3).unwrap();
+		bot.bot = Some(BotDifficulty::Hard);
+		bot.session_token = None;
+
+		let lobby = finished_game.rematch(finished_game.host, false).unwrap();
+
+		let player2 = lobby.players.iter().find(|x| x.id == player2).unwrap();
+		assert_eq!(player2.bot, None);
+		let player3 = lobby.players.iter().find(|x| x.id == player3).unwrap();
+		assert_eq!(player3.bot, Some(BotDifficulty::Hard));
+		assert_eq!(player3.session_token(), None);
+	}
 
-		#[test]
-		fn returns_error_when_moves_possible() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn current_state_tells_players_about_rematch() {
+		let (mut finished_game, player2, _) = finish_game();
+		let lobby = finished_game.rematch(player2, false).unwrap();
+		let token = lobby.players.iter().find(|x| x.id == player2).unwrap().session_token().unwrap().to_string();
+		let game = Game::Finished(finished_game);
+
+		let current_state = rest_api::current_state(&game, Some(player2)).unwrap();
+		assert_eq!(current_state["rematch"]["game_id"], lobby.id.to_string());
+		assert_eq!(current_state["rematch"]["invite_code"], lobby.invite_code);
+		assert_eq!(current_state["rematch"]["session_token"], token);
+
+		let spectator_state = rest_api::spectator_state(&game);
+		assert_eq!(spectator_state["rematch"]["game_id"], lobby.id.to_string());
+		assert!(spectator_state["rematch"]["invite_code"].is_null());
+		assert!(spectator_state["rematch"]["session_token"].is_null());
+	}
+}
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+mod series {
+	use super::*;
+	use crate::game::settings::GameSettings;
+	use crate::game::series::DESTINATION_BONUS;
+
+	fn start_series() -> InProgressGame {
+		let mut lobby = Lobby::create("test_1".to_string(), Some(11));
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		lobby.set_settings(lobby.host, GameSettings { series: true, ..Default::default() }).unwrap();
+		return lobby.start(lobby.host).unwrap();
+	}
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Nantes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+	fn finish_round(game: &InProgressGame, turn: usize, coins: usize, win_condition: WinCondition) -> FinishedGame {
+		let mut game = game.clone();
+		game.turn = turn;
+		game.coins_runner = coins;
+		return FinishedGame::from_in_progress_game(&game, Team::Runner, win_condition);
+	}
 
-			let move_made = Move {
-				player_id: game.current_turn,
-				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string()],
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+	#[test]
+	fn off_by_default() {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ValidMovePossible.to_string());
-		}
+		let game = lobby.start(lobby.host).unwrap();
 
-		#[test]
-		fn returns_error_when_throwing_too_many_away() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+		assert!(game.series.is_none());
+	}
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+	#[test]
+	fn every_player_runs_once_starting_with_first_runner() {
+		let game = start_series();
+		let series = game.series.clone().unwrap();
+
+		assert_eq!(series.runner_order.len(), 3);
+		assert_eq!(series.runner_order[0], game.runner);
+		for player in &game.players {
+			assert!(series.runner_order.contains(&player.id));
+		}
+		assert!(series.results.is_empty());
+	}
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+	#[test]
+	fn finished_round_gets_scored() {
+		let game = start_series();
 
-			let move_made = Move {
-				player_id: game.current_turn,
-				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string(), "high_speed".to_string()],
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+		let caught = finish_round(&game, 7, 4, WinCondition::RunnerCaught);
+		let result = &caught.series.as_ref().unwrap().results[0];
+		assert_eq!((result.round, result.runner, result.turns_survived, result.coins, result.reached_destination, result.score), (1, game.runner, 7, 4, false, 11));
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ThrewTooManyTimetableCardsAway.to_string());
+		let arrived = finish_round(&game, 7, 10, WinCondition::GotToDestination);
+		let result = &arrived.series.as_ref().unwrap().results[0];
+		assert!(result.reached_destination);
+		assert_eq!(result.score, 7 + 10 + DESTINATION_BONUS);
+	}
+
+	#[test]
+	fn next_round_rotates_runner_and_keeps_sessions() {
+		let game = start_series();
+		let finished_game = finish_round(&game, 5, 2, WinCondition::RunnerCaught);
+		let runner_order = game.series.clone().unwrap().runner_order;
+
+		let next_round = finished_game.next_round(game.host).unwrap();
+
+		assert_eq!(next_round.id, game.id);
+		assert_eq!(next_round.runner, runner_order[1]);
+		assert_eq!(next_round.current_turn, runner_order[1]);
+		assert_eq!(next_round.turn, 1);
+		assert_eq!(next_round.coins_runner, 0);
+		assert!(next_round.history.is_empty());
+		assert_eq!(next_round.series.unwrap().results.len(), 1);
+		for player in &next_round.players {
+			let old_player = game.players.iter().find(|x| x.id == player.id).unwrap();
+			assert_eq!(player.session_token(), old_player.session_token());
+			assert_eq!(player.timetable_cards.len(), 5);
 		}
+	}
 
-		#[test]
-		fn returns_error_when_player_doesnt_have_card() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+	#[test]
+	fn next_round_seed_doesnt_follow_from_first_seed() {
+		let game = start_series();
+		let other_game = start_series();
+		assert_eq!(game.seed, other_game.seed);
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+		let next_round = finish_round(&game, 5, 2, WinCondition::RunnerCaught).next_round(game.host).unwrap();
+		let other_next_round = finish_round(&other_game, 5, 2, WinCondition::RunnerCaught).next_round(other_game.host).unwrap();
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+		assert_ne!(next_round.seed, other_next_round.seed);
+	}
 
-			let move_made = Move {
-				player_id: game.current_turn,
-				throw_timetable_cards_away: vec!["plane".to_string(), "joker".to_string()],
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+	#[test]
+	fn only_host_starts_next_round() {
+		let game = start_series();
+		let finished_game = finish_round(&game, 5, 2, WinCondition::RunnerCaught);
+		let player = game.players.iter().find(|x| x.id != game.host).unwrap().id;
 
-			assert!(res.is_err());
-			assert_eq!(res.err().unwrap().to_string(), crate::CustomError::MissingTimetableCard.to_string());
-		}
+		let res = finished_game.next_round(player);
 
-		#[test]
-		fn doesnt_return_error_when_no_moves_possible() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+		assert!(res.is_err());
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::ActionNotAllowed.to_string());
+	}
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::Joker];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+	#[test]
+	fn no_next_round_without_series() {
+		let mut game = start_series();
+		game.series = None;
+		let finished_game = finish_round(&game, 5, 2, WinCondition::RunnerCaught);
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+		let res = finished_game.next_round(game.host);
 
-			let move_made = Move {
-				player_id: game.current_turn,
-				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string()],
-				finish_move: true,
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+		assert!(res.is_err());
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidGameState.to_string());
+	}
 
-			assert!(res.is_ok());
+	#[test]
+	fn series_ends_after_everyone_ran_and_sums_up_scores() {
+		let mut game = start_series();
+		let runner_order = game.series.clone().unwrap().runner_order;
+
+		let mut finished_game = finish_round(&game, 3, 0, WinCondition::RunnerCaught);
+		for turn in [5, 8] {
+			game = finished_game.next_round(game.host).unwrap();
+			finished_game = finish_round(&game, turn, 1, WinCondition::RunnerCaught);
 		}
 
-		#[test]
-		fn actually_replaces_cards() {
-			let mut game = Lobby::create("test_1".to_string());
-			let player2 = game.join("test_2".to_string()).unwrap();
-			let player3 = game.join("test_3".to_string()).unwrap();
-			let mut game = game.start(game.host).unwrap();
+		let series = finished_game.series.clone().unwrap();
+		assert!(series.is_over());
+		assert_eq!(series.results.iter().map(|x| x.runner).collect::<Vec<_>>(), runner_order);
+		let scoreboard = series.scoreboard();
+		assert_eq!(scoreboard[&runner_order[0]], 3);
+		assert_eq!(scoreboard[&runner_order[1]], 6);
+		assert_eq!(scoreboard[&runner_order[2]], 9);
 
-			game.current_turn = game.host;
-			game.runner = game.host;
-			game.timetable_card_stack = vec![TimetableCard::LowSpeed; 5];
-			game.coins_chasers = 5;
-			game.coins_runner = 5;
+		let res = finished_game.next_round(game.host);
+		assert!(res.is_err());
+		assert_eq!(res.err().unwrap().to_string(), crate::CustomError::InvalidGameState.to_string());
+	}
 
-			game.players = vec![
-				Player { id: game.host, display_name: "test_1".to_string(), current_location: Location::Rennes, timetable_cards: vec![TimetableCard::Plane, TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::HighSpeed], ..Default::default() },
-				Player { id: player2, display_name: "test_2".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-				Player { id: player3, display_name: "test_3".to_string(), current_location: Location::Nancy, timetable_cards: vec![TimetableCard::LowSpeed; 5], ..Default::default() },
-			];
+	#[test]
+	fn current_state_shows_series() {
+		let game = start_series();
+		let runner_order = game.series.clone().unwrap().runner_order;
+
+		let current_state = rest_api::current_state(&Game::InProgress(game.clone()), Some(game.host)).unwrap();
+		assert_eq!(current_state["series"]["round"], 1);
+		assert_eq!(current_state["series"]["rounds"], 3);
+		assert_eq!(current_state["series"]["over"], false);
+
+		let finished_game = finish_round(&game, 4, 1, WinCondition::RunnerCaught);
+		let current_state = rest_api::current_state(&Game::Finished(finished_game), Some(game.host)).unwrap();
+		assert_eq!(current_state["series"]["round"], 1);
+		assert_eq!(current_state["series"]["results"][0]["score"], 5);
+		assert_eq!(current_state["series"]["scoreboard"][runner_order[0].to_string()], 5);
+		assert_eq!(current_state["series"]["scoreboard"][runner_order[1].to_string()], 0);
+	}
+}
 
-			let move_made = Move {
-				player_id: game.current_turn,
-				throw_timetable_cards_away: vec!["plane".to_string(), "high_speed".to_string()],
-				..Default::default()
-			};
-			let res = game.make_move(move_made);
+mod logging {
+	use super::*;
 
-			assert!(res.is_ok());
-			assert_eq!(game.players.iter().find(|x| x.id == game.current_turn).unwrap().timetable_cards, vec![TimetableCard::Plane, TimetableCard::HighSpeed, TimetableCard::LowSpeed, TimetableCard::LowSpeed]);
-		}
+	fn move_span(line: &serde_json::Value) -> serde_json::Value {
+		return line["spans"].as_array().unwrap().iter().find(|x| x["name"] == "move").cloned().unwrap();
+	}
+
+	#[test]
+	fn accepted_move_logs_fields_and_result() {
+		//player 2 catches the runner in Zaragoza with a low speed card
+		let (mut game, player2, _) = TestGame::new().player(0, Location::Zaragoza, vec![TimetableCard::LowSpeed; 5]).player(1, Location::Madrid, vec![TimetableCard::LowSpeed; 5]).timetable_card_stack(vec![TimetableCard::Joker; 5]).start();
+		game.current_turn = player2;
+		game.destination = Location::Madrid;
+		let mut game = Game::InProgress(game);
+		let game_id = game.id();
+
+		let lines = crate::logging::capture(|| {
+			game.make_move(Move { player_id: player2, use_timetable_card: Some("low_speed".to_string()), next_location: Some("zaragoza".to_string()), ..Default::default() }).unwrap();
+		});
+
+		let line = lines.iter().find(|x| x["message"] == "move made").unwrap();
+		let span = move_span(line);
+		assert_eq!(span["game_id"], game_id.to_string());
+		assert_eq!(span["player_id"], player2.to_string());
+		assert_eq!(span["next_location"], "zaragoza");
+		assert_eq!(span["use_timetable_card"], "low_speed");
+		assert_eq!(span["buy_event_card"], false);
+		assert_eq!(span["finish_move"], false);
+		assert!(span.get("use_event_card").is_none());
+
+		let result: serde_json::Value = serde_json::from_str(line["result"].as_str().unwrap()).unwrap();
+		assert_eq!(result["runner_caught"], true);
+	}
 
+	#[test]
+	fn finished_game_is_logged_without_session_tokens() {
+		//player 2 catches the runner in Zaragoza with a low speed card
+		let (mut game, player2, _) = TestGame::new().player(0, Location::Zaragoza, vec![TimetableCard::LowSpeed; 5]).player(1, Location::Madrid, vec![TimetableCard::LowSpeed; 5]).timetable_card_stack(vec![TimetableCard::Joker; 5]).start();
+		game.current_turn = player2;
+		game.destination = Location::Madrid;
+		let session_tokens: Vec<String> = game.players.iter().map(|x| x.session_token().unwrap().to_string()).collect();
+		let mut game = Game::InProgress(game);
+
+		let lines = crate::logging::capture(|| {
+			game.make_move(Move { player_id: player2, use_timetable_card: Some("low_speed".to_string()), next_location: Some("zaragoza".to_string()), ..Default::default() }).unwrap();
+		});
+
+		let line = lines.iter().find(|x| x["message"] == "game finished").unwrap();
+		assert_eq!(line["win_condition"], "RunnerCaught");
+		assert_eq!(line["winning_team"], "Chaser");
+		let output = lines.iter().map(|x| x.to_string()).collect::<Vec<String>>().join("\n");
+		assert!(session_tokens.iter().all(|x| !output.contains(x.as_str())));
+	}
 
+	#[test]
+	fn rejected_move_logs_error() {
+		let (mut game, player2, _) = TestGame::new().start();
+		game.current_turn = player2;
+		let host = game.host;
+		let mut game = Game::InProgress(game);
+
+		let lines = crate::logging::capture(|| {
+			assert!(game.make_move(Move { player_id: host, next_location: Some("madrid".to_string()), ..Default::default() }).is_err());
+		});
+
+		assert_eq!(lines.len(), 1);
+		assert_eq!(lines[0]["message"], "move rejected");
+		assert_eq!(lines[0]["error_id"], "not_your_turn");
+		assert_eq!(lines[0]["error"], crate::CustomError::NotYourTurn.to_string());
+		assert_eq!(move_span(&lines[0])["player_id"], host.to_string());
+		assert_eq!(move_span(&lines[0])["next_location"], "madrid");
 	}
 
+	#[test]
+	fn moves_outside_of_in_progress_games_are_rejected_too() {
+		let mut game = Game::Lobby(Lobby::create("test_1".to_string(), None));
+		let host = game.players()[0].id();
+
+		let lines = crate::logging::capture(|| {
+			assert!(game.make_move(Move { player_id: host, ..Default::default() }).is_err());
+		});
 
-}
\ No newline at end of file
+		assert_eq!(lines.len(), 1);
+		assert_eq!(lines[0]["error_id"], "invalid_game_state");
+	}
+}
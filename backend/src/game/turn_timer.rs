@@ -0,0 +1,133 @@
+use actix_web::web;
+use crate::rest_api::AppState;
+use crate::game_store::GameStore;
+use super::*;
+use super::rest_api::persist_game;
+
+// How often the timer looks for turns that ran out of time
+const TICK: std::time::Duration = std::time::Duration::from_secs(1);
+// Bots play right away, a bot turn that is still open after this many milliseconds failed and gets ended for it
+const STUCK_BOT_TURN: u64 = 10_000;
+
+impl InProgressGame {
+	// Unix time in milliseconds the current turn has to be finished by, None when there is no time limit.
+	// Games saved before the limit had a maximum can have a limit too long to count in milliseconds, that is no limit either
+	pub fn turn_deadline(&self) -> Option<u64> {
+		return self.settings.turn_time_limit.and_then(|x| x.checked_mul(1000)).map(|x| self.turn_started_at.saturating_add(x));
+	}
+
+	// A player who timed out and got replaced by a bot plays again themselves.
+	// Real bots have no session, so only replaced players can get here
+	pub fn take_back(&mut self, player_id: PlayerId) -> Result<(), Box<dyn Error>> {
+		match self.players.iter_mut().find(|x| x.id == player_id) {
+			Some(player) if player.bot.is_some() => {
+				player.bot = None;
+				player.timeouts = 0;
+			},
+			Some(_) => return Err(Box::new(crate::CustomError::ActionNotAllowed)),
+			None => return Err(Box::new(crate::CustomError::UnknownPlayer)),
+		}
+
+		return Ok(());
+	}
+}
+
+impl Game {
+	// Finishes the turn of a player who ran out of time the way an easy bot would: a legal move if there is one,
+	// otherwise throwing timetable cards away. After timeouts_until_bot timeouts in a row a bot takes over the player.
+	// Bot turns that got stuck because the bot's move failed are finished the same way, with or without a time limit.
+	// Returns whether the game changed
+	pub fn time_out_turn(&mut self, now: u64) -> bool {
+		let (player_id, is_bot, timeouts, move_made) = match self {
+			Game::InProgress(game) => {
+				let player = game.players.iter().find(|x| x.id == game.current_turn).unwrap();
+				let is_bot = player.bot.is_some();
+				let deadline = if is_bot {Some(game.turn_started_at.saturating_add(STUCK_BOT_TURN))} else {game.turn_deadline()};
+				if deadline.is_none_or(|x| now < x) {
+					return false;
+				}
+
+				(player.id, is_bot, player.timeouts + 1, game.next_bot_move(BotDifficulty::Easy))
+			},
+			_ => return false,
+		};
+
+		if let Err(e) = self.make_move(move_made) {
+			tracing::warn!(game_id = %self.id(), %player_id, error = %e, "couldn't finish the turn after it ran out of time");
+			//tries again once the time limit ran out again instead of on every tick
+			if let Game::InProgress(game) = self {
+				game.turn_started_at = now;
+			}
+			return true;
+		}
+
+		//a stuck bot didn't run out of time, it already is a bot
+		if let (Game::InProgress(game), false) = (&mut *self, is_bot) {
+			let player = game.players.iter_mut().find(|x| x.id == player_id).unwrap();
+			player.timeouts = timeouts;
+			if timeouts >= game.settings.timeouts_until_bot {
+				player.bot = Some(BotDifficulty::Easy);
+			}
+		}
+
+		self.play_bot_turns();
+
+		return true;
+	}
+}
+
+// Finishes every turn that ran out of time and returns the ids of the games that changed.
+// Games that are locked right now are busy and get checked on the next tick.
+// Bots and persisting can take a while, so it must not run on the async runtime
+pub fn time_out_turns(games: &dyn GameStore, now: u64) -> Vec<GameId> {
+	let mut changed = Vec::new();
+
+	for game_id in games.game_ids() {
+		let game = match games.get(&game_id) {
+			Some(game) => game,
+			None => continue,
+		};
+		let mut game = match game.try_lock_owned() {
+			Ok(game) => game,
+			Err(_) => continue,
+		};
+
+		//a game that panics must not stop the timer for every other game
+		match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| game.time_out_turn(now))) {
+			Ok(true) => {
+				persist_game(games, &mut game);
+				changed.push(game_id);
+			},
+			Ok(false) => (),
+			Err(_) => tracing::error!(%game_id, "finishing the timed out turn panicked"),
+		}
+	}
+
+	return changed;
+}
+
+// Runs for as long as the server does and finishes every turn that ran out of time
+pub async fn run(data: web::Data<AppState>) {
+	let mut interval = actix_web::rt::time::interval(TICK);
+
+	loop {
+		interval.tick().await;
+		//games must not change anymore while they are flushed
+		if data.shutting_down.load(std::sync::atomic::Ordering::SeqCst) {
+			return;
+		}
+
+		let store = data.clone();
+		let changed = match web::block(move || time_out_turns(store.games.as_ref(), history::now())).await {
+			Ok(changed) => changed,
+			Err(e) => {
+				tracing::error!(error = %e, "couldn't finish timed out turns");
+				continue;
+			},
+		};
+
+		for game_id in changed {
+			data.game_updates.notify(&game_id);
+		}
+	}
+}
@@ -0,0 +1,128 @@
+use actix_web::{get, web, HttpRequest, HttpResponse};
+use actix_ws::Message;
+use std::sync::atomic::Ordering;
+use tokio::sync::broadcast;
+use crate::rest_api::AppState;
+use crate::rest_api::error::ErrorResponse;
+use crate::rest_api::session::{self, Session};
+use crate::CustomError;
+use serde::Serialize;
+use tracing::Instrument;
+use super::*;
+use super::in_progress_game::{Move, MoveResult};
+use super::rest_api::{current_state_for, lock_game, persist_game};
+use super::spectator::Participant;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+enum WebsocketMessage {
+	CurrentState(serde_json::Value),
+	MoveResult(Box<MoveResult>),
+	Error(ErrorResponse),
+}
+
+// Pushes the current_state view of the game to the player every time it changes.
+// Text messages sent by the client are handled like the body of make_move,
+// the player is taken from the session of the connection. Spectators can connect as well, but can't make moves.
+#[get("/api/v1/games/{game_id}/ws")]
+pub async fn connect(req: HttpRequest, stream: web::Payload, data: web::Data<AppState>, game_id: web::Path<Uuid>, session: Session) -> Result<HttpResponse, actix_web::Error> {
+	let game_id = game_id.into_inner();
+	let participant = session.participant_in(&game_id)?;
+
+	//makes sure the game still exists before upgrading the connection
+	drop(lock_game(&data, &game_id).await?);
+
+	let (mut response, session, msg_stream) = actix_ws::handle(&req, stream)?;
+	//browsers close the connection if the server doesn't agree to one of the subprotocols they asked for
+	if req.headers().get("Authorization").is_none() {
+		response.headers_mut().insert(actix_web::http::header::SEC_WEBSOCKET_PROTOCOL, actix_web::http::header::HeaderValue::from_static(session::WEBSOCKET_PROTOCOL));
+	}
+	let updates = data.game_updates.subscribe(&game_id);
+
+	//moves over the websocket get logged with the request that opened it
+	actix_web::rt::spawn(run_session(data, game_id, participant, session, msg_stream, updates).instrument(tracing::Span::current()));
+
+	return Ok(response);
+}
+
+async fn run_session(data: web::Data<AppState>, game_id: GameId, participant: Participant, mut session: actix_ws::Session, mut msg_stream: actix_ws::MessageStream, mut updates: broadcast::Receiver<()>) {
+	if send(&mut session, &get_current_state(&data, &game_id, participant).await).await.is_err() {
+		return;
+	}
+
+	loop {
+		let message = tokio::select! {
+			msg = msg_stream.recv() => match msg {
+				Some(Ok(Message::Text(text))) => match participant {
+					Participant::Player(player_id) => handle_move(&data, &game_id, player_id, &text).await,
+					Participant::Spectator(_) => WebsocketMessage::Error(ErrorResponse::from(&CustomError::ActionNotAllowed)),
+				},
+				Some(Ok(Message::Ping(bytes))) => {
+					if session.pong(&bytes).await.is_err() {
+						break;
+					}
+					continue;
+				},
+				Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+				Some(Ok(_)) => continue,
+			},
+			update = updates.recv() => match update {
+				_ if data.shutting_down.load(Ordering::SeqCst) => break,
+				Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => get_current_state(&data, &game_id, participant).await,
+				Err(broadcast::error::RecvError::Closed) => break,
+			},
+		};
+
+		if send(&mut session, &message).await.is_err() {
+			return;
+		}
+	}
+
+	//tells clients to connect again once the server is back
+	let reason = if data.shutting_down.load(Ordering::SeqCst) {Some(actix_ws::CloseCode::Restart.into())} else {None};
+	let _ = session.close(reason).await;
+}
+
+async fn send(session: &mut actix_ws::Session, message: &WebsocketMessage) -> Result<(), actix_ws::Closed> {
+	return session.text(serde_json::to_string(message).unwrap()).await;
+}
+
+async fn get_current_state(data: &web::Data<AppState>, game_id: &GameId, participant: Participant) -> WebsocketMessage {
+	return match lock_game(data, game_id).await {
+		Ok(game) => match current_state_for(&game, Some(participant)) {
+			Ok(current_state) => WebsocketMessage::CurrentState(current_state),
+			Err(e) => WebsocketMessage::Error(ErrorResponse::from(&e)),
+		},
+		Err(e) => WebsocketMessage::Error(ErrorResponse::from(&e)),
+	};
+}
+
+async fn handle_move(data: &web::Data<AppState>, game_id: &GameId, player_id: PlayerId, text: &str) -> WebsocketMessage {
+	let mut body: serde_json::Value = match serde_json::from_str(text) {
+		Ok(body) => body,
+		Err(e) => return WebsocketMessage::Error(ErrorResponse::from(&CustomError::InvalidRequestBody(e.to_string()))),
+	};
+	if let Some(body) = body.as_object_mut() {
+		body.insert("player_id".to_string(), serde_json::Value::String(player_id.to_string()));
+	}
+	let move_made: Move = match serde_json::from_value(body) {
+		Ok(move_made) => move_made,
+		Err(e) => return WebsocketMessage::Error(ErrorResponse::from(&CustomError::InvalidRequestBody(e.to_string()))),
+	};
+
+	let mut game = match lock_game(data, game_id).await {
+		Ok(game) => game,
+		Err(e) => return WebsocketMessage::Error(ErrorResponse::from(&e)),
+	};
+
+	return match game.make_move(move_made) {
+		Ok(res) => {
+			game.play_bot_turns();
+			persist_game(data.games.as_ref(), &mut game);
+			drop(game);
+			data.game_updates.notify(game_id);
+			WebsocketMessage::MoveResult(Box::new(res))
+		},
+		Err(e) => WebsocketMessage::Error(ErrorResponse::from(&e)),
+	};
+}
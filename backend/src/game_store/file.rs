@@ -0,0 +1,257 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::collections::BTreeMap;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use super::*;
+
+// After this many appended log entries the log gets folded into a new snapshot
+const MAX_LOG_ENTRIES: usize = 500;
+
+// Keeps all games in memory like InMemoryGameStore, but also writes them to disk.
+// snapshot.json contains every game at the time of the last compaction and
+// games.log gets one line with the complete game appended every time a game is persisted.
+// On startup the snapshot is loaded and the log replayed on top of it, later lines win.
+// Only the writer thread touches the files, requests hand it the serialized game and never wait for the disk
+#[derive(Debug)]
+pub struct FileGameStore {
+	games: InMemoryGameStore,
+	writer: mpsc::Sender<Command>,
+	writer_thread: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug)]
+struct GameFile {
+	snapshot_path: PathBuf,
+	log_path: PathBuf,
+	log: File,
+	log_entries: usize,
+	//the last written version of every game, so compacting doesn't need to lock any game
+	latest: BTreeMap<GameId, String>,
+}
+
+// What the writer thread gets told to do, in the order the store was changed
+#[derive(Debug)]
+enum Command {
+	Write(GameId, String),
+	Remove(Vec<GameId>),
+	//answers once everything sent before is on disk
+	Compact(mpsc::Sender<Result<(), String>>),
+	#[cfg(test)]
+	WaitForWrites(mpsc::Sender<()>),
+	Stop,
+}
+
+impl FileGameStore {
+	pub fn open(dir: &Path) -> Result<Self, Box<dyn Error>> {
+		fs::create_dir_all(dir)?;
+
+		let snapshot_path = dir.join("snapshot.json");
+		let log_path = dir.join("games.log");
+
+		let mut games: BTreeMap<GameId, Game> = BTreeMap::new();
+
+		if snapshot_path.exists() {
+			let snapshot: Vec<Game> = serde_json::from_reader(BufReader::new(File::open(&snapshot_path)?))?;
+			for game in snapshot {
+				games.insert(game.id(), game);
+			}
+		}
+
+		if log_path.exists() {
+			for line in BufReader::new(File::open(&log_path)?).lines() {
+				let line = line?;
+				if line.is_empty() {
+					continue;
+				}
+
+				match serde_json::from_str::<Game>(&line) {
+					Ok(game) => {
+						games.insert(game.id(), game);
+					},
+					//the last line might be cut off if the process died while writing it
+					Err(e) => tracing::warn!(path = %log_path.display(), error = %e, "skipping unreadable line"),
+				}
+			}
+		}
+
+		let log = OpenOptions::new().create(true).append(true).open(&log_path)?;
+
+		let mut file = GameFile {
+			snapshot_path,
+			log_path,
+			log,
+			log_entries: 0,
+			latest: BTreeMap::new(),
+		};
+
+		let store = InMemoryGameStore::new();
+		for game in games.into_values() {
+			file.latest.insert(game.id(), serde_json::to_string(&game)?);
+			store.insert(game)?;
+		}
+
+		file.compact()?;
+
+		let (writer, commands) = mpsc::channel();
+		let writer_thread = std::thread::Builder::new().name("game-writer".to_string()).spawn(move || file.run(commands))?;
+
+		return Ok(Self {
+			games: store,
+			writer,
+			writer_thread: Some(writer_thread),
+		});
+	}
+
+	// Folds the log into the snapshot and waits until that's done
+	pub fn compact(&self) -> Result<(), Box<dyn Error>> {
+		let (done, result) = mpsc::channel();
+		self.send(Command::Compact(done))?;
+		return match result.recv() {
+			Ok(res) => res.map_err(|e| e.into()),
+			Err(_) => Err("the game writer stopped".into()),
+		};
+	}
+
+	#[cfg(test)]
+	pub fn wait_for_writes(&self) {
+		let (done, result) = mpsc::channel();
+		self.send(Command::WaitForWrites(done)).unwrap();
+		result.recv().unwrap();
+	}
+
+	fn send(&self, command: Command) -> Result<(), Box<dyn Error>> {
+		return self.writer.send(command).map_err(|_| "the game writer stopped".into());
+	}
+}
+
+impl Drop for FileGameStore {
+	// Everything that was persisted is written before the store is gone
+	fn drop(&mut self) {
+		let _ = self.writer.send(Command::Stop);
+		if let Some(writer_thread) = self.writer_thread.take() {
+			let _ = writer_thread.join();
+		}
+	}
+}
+
+impl GameFile {
+	// The writer thread, runs until the store gets dropped
+	fn run(mut self, commands: mpsc::Receiver<Command>) {
+		while let Ok(command) = commands.recv() {
+			let res = match command {
+				Command::Write(game_id, json) => self.append_to_log(game_id, json),
+				//the log can't express a removal, so the snapshot gets rewritten without the games right away
+				Command::Remove(game_ids) => {
+					for game_id in &game_ids {
+						self.latest.remove(game_id);
+					}
+					self.compact()
+				},
+				Command::Compact(done) => {
+					let _ = done.send(self.compact().map_err(|e| e.to_string()));
+					continue;
+				},
+				#[cfg(test)]
+				Command::WaitForWrites(done) => {
+					let _ = done.send(());
+					continue;
+				},
+				Command::Stop => return,
+			};
+
+			if let Err(e) = res {
+				tracing::error!(path = %self.log_path.display(), error = %e, "couldn't write games to disk");
+			}
+		}
+	}
+
+	fn compact(&mut self) -> Result<(), Box<dyn Error>> {
+		let tmp_path = self.snapshot_path.with_extension("json.tmp");
+		let mut tmp_file = File::create(&tmp_path)?;
+		tmp_file.write_all(b"[")?;
+		for (i, game) in self.latest.values().enumerate() {
+			if i != 0 {
+				tmp_file.write_all(b",")?;
+			}
+			tmp_file.write_all(game.as_bytes())?;
+		}
+		tmp_file.write_all(b"]")?;
+		tmp_file.sync_all()?;
+		fs::rename(&tmp_path, &self.snapshot_path)?;
+
+		self.log = File::create(&self.log_path)?;
+		self.log_entries = 0;
+
+		return Ok(());
+	}
+
+	fn append_to_log(&mut self, game_id: GameId, json: String) -> Result<(), Box<dyn Error>> {
+		self.log.write_all(format!("{json}\n").as_bytes())?;
+		self.log.flush()?;
+		self.log_entries += 1;
+		self.latest.insert(game_id, json);
+
+		if self.log_entries >= MAX_LOG_ENTRIES {
+			self.compact()?;
+		}
+
+		return Ok(());
+	}
+}
+
+impl GameStore for FileGameStore {
+	fn get(&self, game_id: &GameId) -> Option<GameHandle> {
+		return self.games.get(game_id);
+	}
+
+	fn game_ids(&self) -> Vec<GameId> {
+		return self.games.game_ids();
+	}
+
+	fn len(&self) -> usize {
+		return self.games.len();
+	}
+
+	fn game_counts(&self) -> GameCounts {
+		return self.games.game_counts();
+	}
+
+	fn find_lobby_by_invite_code(&self, invite_code: &str) -> Option<GameHandle> {
+		return self.games.find_lobby_by_invite_code(invite_code);
+	}
+
+	fn find_game_by_spectator_invite_code(&self, invite_code: &str) -> Option<GameHandle> {
+		return self.games.find_game_by_spectator_invite_code(invite_code);
+	}
+
+	fn find_session(&self, session_token: &str) -> Option<(GameId, Participant)> {
+		return self.games.find_session(session_token);
+	}
+
+	//serializing is the only work done here, writing happens on the writer thread
+	fn insert(&self, game: Game) -> Result<GameHandle, Box<dyn Error>> {
+		self.send(Command::Write(game.id(), serde_json::to_string(&game)?))?;
+		return self.games.insert(game);
+	}
+
+	fn persist(&self, game: &Game) -> Result<(), Box<dyn Error>> {
+		if self.games.get(&game.id()).is_none() {
+			return Ok(());
+		}
+		self.games.persist(game)?;
+		return self.send(Command::Write(game.id(), serde_json::to_string(game)?));
+	}
+
+	//all games of one sweep cost a single snapshot rewrite
+	fn remove(&self, game_ids: &[GameId]) -> Result<(), Box<dyn Error>> {
+		self.games.remove(game_ids)?;
+		return self.send(Command::Remove(game_ids.to_vec()));
+	}
+
+	//the next start only has to read the snapshot, which is synced to disk
+	fn flush(&self) -> Result<(), Box<dyn Error>> {
+		return self.compact();
+	}
+}
@@ -0,0 +1,178 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::RwLock;
+use crate::metrics::Phase;
+use super::*;
+
+#[derive(Debug, Default)]
+pub struct InMemoryGameStore {
+	games: RwLock<BTreeMap<GameId, GameHandle>>,
+	//only contains lobbies, codes get removed once the game is started
+	invite_codes: RwLock<BTreeMap<String, GameId>>,
+	//spectator invite codes work as long as the game exists
+	spectator_invite_codes: RwLock<BTreeMap<String, GameId>>,
+	//session token -> the game and player or spectator it belongs to, tokens stay valid until the game is gone
+	sessions: RwLock<BTreeMap<String, (GameId, Participant)>>,
+	//game -> what it's indexed under, so persisting and removing a game only touches its own entries
+	indexed: RwLock<BTreeMap<GameId, Indexed>>,
+	game_counts: RwLock<GameCounts>,
+}
+
+#[derive(Debug)]
+struct Indexed {
+	phase: Phase,
+	invite_code: Option<String>,
+	spectator_invite_code: String,
+	session_tokens: BTreeSet<String>,
+}
+
+impl InMemoryGameStore {
+	pub fn new() -> Self {
+		return Self::default();
+	}
+}
+
+impl InMemoryGameStore {
+	fn session_tokens(game: &Game) -> BTreeSet<String> {
+		let players = game.players().iter().filter_map(|x| x.session_token().map(|x| x.to_string()));
+		let spectators = game.spectators().spectators.iter().map(|x| x.session_token().to_string());
+		return players.chain(spectators).collect();
+	}
+
+	fn index_sessions(&self, game: &Game, old_tokens: &BTreeSet<String>) -> BTreeSet<String> {
+		let new_tokens = Self::session_tokens(game);
+		let mut sessions = self.sessions.write().unwrap();
+		//players that left the lobby lose their session
+		for session_token in old_tokens.difference(&new_tokens) {
+			sessions.remove(session_token);
+		}
+		for player in game.players() {
+			if let Some(session_token) = player.session_token() {
+				sessions.insert(session_token.to_string(), (game.id(), Participant::Player(player.id())));
+			}
+		}
+		for spectator in &game.spectators().spectators {
+			sessions.insert(spectator.session_token().to_string(), (game.id(), Participant::Spectator(spectator.id)));
+		}
+		return new_tokens;
+	}
+}
+
+impl GameStore for InMemoryGameStore {
+	fn get(&self, game_id: &GameId) -> Option<GameHandle> {
+		return self.games.read().unwrap().get(game_id).cloned();
+	}
+
+	fn game_ids(&self) -> Vec<GameId> {
+		return self.games.read().unwrap().keys().copied().collect();
+	}
+
+	fn len(&self) -> usize {
+		return self.games.read().unwrap().len();
+	}
+
+	fn game_counts(&self) -> GameCounts {
+		return *self.game_counts.read().unwrap();
+	}
+
+	fn find_lobby_by_invite_code(&self, invite_code: &str) -> Option<GameHandle> {
+		let game_id = *self.invite_codes.read().unwrap().get(invite_code)?;
+		return self.get(&game_id);
+	}
+
+	fn find_game_by_spectator_invite_code(&self, invite_code: &str) -> Option<GameHandle> {
+		let game_id = *self.spectator_invite_codes.read().unwrap().get(invite_code)?;
+		return self.get(&game_id);
+	}
+
+	fn find_session(&self, session_token: &str) -> Option<(GameId, Participant)> {
+		return self.sessions.read().unwrap().get(session_token).copied();
+	}
+
+	fn insert(&self, game: Game) -> Result<GameHandle, Box<dyn Error>> {
+		let game_id = game.id();
+		let invite_code = match &game {
+			Game::Lobby(lobby) => Some(lobby.invite_code.clone()),
+			_ => None,
+		};
+		if let Some(invite_code) = &invite_code {
+			self.invite_codes.write().unwrap().insert(invite_code.clone(), game_id);
+		}
+		let spectator_invite_code = game.spectators().invite_code.clone();
+		self.spectator_invite_codes.write().unwrap().insert(spectator_invite_code.clone(), game_id);
+		let session_tokens = self.index_sessions(&game, &BTreeSet::new());
+		let phase = Phase::of(&game);
+		self.indexed.write().unwrap().insert(game_id, Indexed { phase, invite_code, spectator_invite_code, session_tokens });
+		*self.game_counts.write().unwrap().of_phase(phase) += 1;
+
+		let handle: GameHandle = Arc::new(tokio::sync::Mutex::new(game));
+		self.games.write().unwrap().insert(game_id, handle.clone());
+
+		return Ok(handle);
+	}
+
+	fn persist(&self, game: &Game) -> Result<(), Box<dyn Error>> {
+		//a request that got the game before it was removed must not bring its sessions back
+		if self.get(&game.id()).is_none() {
+			return Ok(());
+		}
+		let phase = Phase::of(game);
+		let session_tokens = Self::session_tokens(game);
+		//most persists are moves, which change neither the phase nor the players or spectators
+		if let Some(indexed) = self.indexed.read().unwrap().get(&game.id()) {
+			if indexed.phase == phase && indexed.session_tokens == session_tokens {
+				return Ok(());
+			}
+		}
+
+		let mut indexed = self.indexed.write().unwrap();
+		let Some(indexed) = indexed.get_mut(&game.id()) else {
+			return Ok(());
+		};
+		if indexed.phase != phase {
+			let mut game_counts = self.game_counts.write().unwrap();
+			*game_counts.of_phase(indexed.phase) -= 1;
+			*game_counts.of_phase(phase) += 1;
+			indexed.phase = phase;
+		}
+		let is_lobby = phase == Phase::Lobby;
+		if !is_lobby {
+			if let Some(invite_code) = indexed.invite_code.take() {
+				self.invite_codes.write().unwrap().remove(&invite_code);
+			}
+		}
+		//picks up the sessions of players that joined the lobby and of new spectators
+		indexed.session_tokens = self.index_sessions(game, &indexed.session_tokens);
+		return Ok(());
+	}
+
+	fn remove(&self, game_ids: &[GameId]) -> Result<(), Box<dyn Error>> {
+		let mut games = self.games.write().unwrap();
+		let mut indexed = self.indexed.write().unwrap();
+		let mut invite_codes = self.invite_codes.write().unwrap();
+		let mut spectator_invite_codes = self.spectator_invite_codes.write().unwrap();
+		let mut sessions = self.sessions.write().unwrap();
+		let mut game_counts = self.game_counts.write().unwrap();
+
+		for game_id in game_ids {
+			games.remove(game_id);
+			let Some(indexed) = indexed.remove(game_id) else {
+				continue;
+			};
+			*game_counts.of_phase(indexed.phase) -= 1;
+			if let Some(invite_code) = indexed.invite_code {
+				invite_codes.remove(&invite_code);
+			}
+			spectator_invite_codes.remove(&indexed.spectator_invite_code);
+			for session_token in indexed.session_tokens {
+				sessions.remove(&session_token);
+			}
+		}
+
+		return Ok(());
+	}
+
+	//games are lost when the server stops anyway
+	fn flush(&self) -> Result<(), Box<dyn Error>> {
+		return Ok(());
+	}
+}
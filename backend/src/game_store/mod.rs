@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod test;
+
+pub mod in_memory;
+pub mod file;
+pub mod sweeper;
+
+use std::error::Error;
+use std::sync::Arc;
+use crate::game::{Game, GameId};
+use crate::game::spectator::Participant;
+use crate::metrics::GameCounts;
+
+pub use in_memory::InMemoryGameStore;
+pub use file::FileGameStore;
+
+// Every game sits behind its own lock. Requests for different games never wait on each other,
+// requests for the same game queue up on its lock. The store itself is only locked while looking a game up.
+pub type GameHandle = Arc<tokio::sync::Mutex<Game>>;
+
+// Everything that needs to look up or change a game goes through a GameStore.
+// Handlers lock the game, change it and then call persist while still holding the lock,
+// so backends that write to disk know when a game changed.
+pub trait GameStore: Send + Sync {
+	fn get(&self, game_id: &GameId) -> Option<GameHandle>;
+	//for background tasks that have to look at every game
+	fn game_ids(&self) -> Vec<GameId>;
+	//number of games, without collecting their ids
+	fn len(&self) -> usize;
+	//kept up to date on every change, so reading them doesn't lock any game
+	fn game_counts(&self) -> GameCounts;
+	fn find_lobby_by_invite_code(&self, invite_code: &str) -> Option<GameHandle>;
+	fn find_game_by_spectator_invite_code(&self, invite_code: &str) -> Option<GameHandle>;
+	fn find_session(&self, session_token: &str) -> Option<(GameId, Participant)>;
+	fn insert(&self, game: Game) -> Result<GameHandle, Box<dyn Error>>;
+	fn persist(&self, game: &Game) -> Result<(), Box<dyn Error>>;
+	//forgets the games together with their invite codes and sessions
+	fn remove(&self, game_ids: &[GameId]) -> Result<(), Box<dyn Error>>;
+	//called once on shutdown, after every change has been persisted
+	fn flush(&self) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,138 @@
+use std::fs;
+use std::path::PathBuf;
+use actix_web::web;
+use crate::game::history;
+use crate::rest_api::AppState;
+use super::*;
+
+// How often the sweeper looks for games nobody touched in a while
+const TICK: std::time::Duration = std::time::Duration::from_secs(60);
+// A year, games nobody touched for longer than that aren't coming back
+pub const MAX_TTL: u64 = 365 * 24 * 60 * 60;
+
+// Seconds a game may go without any change before it gets removed, depending on the phase it is in.
+// Part of the server config, see config.rs
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExpirySettings {
+	pub lobby_ttl: u64,
+	pub in_progress_ttl: u64,
+	pub finished_ttl: u64,
+	//finished games get written to this directory before they are removed
+	pub archive_path: Option<PathBuf>,
+}
+
+impl Default for ExpirySettings {
+	fn default() -> Self {
+		return Self {
+			lobby_ttl: 2 * 60 * 60,
+			in_progress_ttl: 24 * 60 * 60,
+			finished_ttl: 60 * 60,
+			archive_path: None,
+		};
+	}
+}
+
+impl ExpirySettings {
+	pub fn is_expired(&self, game: &Game, now: u64) -> bool {
+		let ttl = match game {
+			Game::Lobby(_) => self.lobby_ttl,
+			Game::InProgress(_) => self.in_progress_ttl,
+			Game::Finished(_) => self.finished_ttl,
+		};
+
+		return now >= game.last_activity().saturating_add(ttl.saturating_mul(1000));
+	}
+}
+
+// Writes the finished game to <archive_path>/<game_id>.json
+fn archive(game: &Game, archive_path: &std::path::Path) -> Result<(), Box<dyn Error>> {
+	if let Game::Finished(finished_game) = game {
+		fs::create_dir_all(archive_path)?;
+		let tmp_path = archive_path.join(format!("{}.json.tmp", finished_game.id));
+		fs::write(&tmp_path, serde_json::to_string(finished_game)?)?;
+		fs::rename(&tmp_path, archive_path.join(format!("{}.json", finished_game.id)))?;
+	}
+
+	return Ok(());
+}
+
+// Removes every expired game from the store and returns their ids.
+// Games that are locked right now are in use and get checked on the next sweep.
+// Does file io, so it must not run on the async runtime
+pub fn sweep(games: &dyn GameStore, settings: &ExpirySettings, now: u64) -> Vec<GameId> {
+	//the expired games stay locked until they are gone, so no request can change them in between
+	let mut expired = Vec::new();
+
+	for game_id in games.game_ids() {
+		let game = match games.get(&game_id) {
+			Some(game) => game,
+			None => continue,
+		};
+		let mut game = match game.try_lock_owned() {
+			Ok(game) => game,
+			Err(_) => continue,
+		};
+
+		//games saved before last_activity existed start their ttl now
+		if game.last_activity() == 0 {
+			game.touch(now);
+			continue;
+		}
+
+		if !settings.is_expired(&game, now) {
+			continue;
+		}
+
+		if let Some(archive_path) = &settings.archive_path {
+			if let Err(e) = archive(&game, archive_path) {
+				//keeps the game instead of losing it
+				tracing::error!(%game_id, error = %e, "couldn't archive game");
+				continue;
+			}
+		}
+
+		expired.push((game_id, game));
+	}
+
+	let removed: Vec<GameId> = expired.iter().map(|(game_id, _)| *game_id).collect();
+	if removed.is_empty() {
+		return removed;
+	}
+
+	return match games.remove(&removed) {
+		Ok(_) => removed,
+		Err(e) => {
+			tracing::error!(games = removed.len(), error = %e, "couldn't remove games");
+			Vec::new()
+		},
+	};
+}
+
+// Runs for as long as the server does and removes games nobody played in a while
+pub async fn run(data: web::Data<AppState>, settings: ExpirySettings) {
+	let mut interval = actix_web::rt::time::interval(TICK);
+
+	loop {
+		interval.tick().await;
+		//games must not change anymore while they are flushed
+		if data.shutting_down.load(std::sync::atomic::Ordering::SeqCst) {
+			return;
+		}
+
+		let (store, expiry) = (data.clone(), settings.clone());
+		let removed = match web::block(move || sweep(store.games.as_ref(), &expiry, history::now())).await {
+			Ok(removed) => removed,
+			Err(e) => {
+				tracing::error!(error = %e, "couldn't sweep games");
+				continue;
+			},
+		};
+
+		for game_id in removed {
+			tracing::info!(%game_id, "removed expired game");
+			//open websockets fetch the game again and find out it is gone
+			data.game_updates.notify(&game_id);
+		}
+	}
+}
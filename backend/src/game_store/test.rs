@@ -0,0 +1,580 @@
+use super::*;
+use crate::game::lobby::Lobby;
+use crate::game::spectator::Participant;
+use crate::metrics::GameCounts;
+use std::path::PathBuf;
+
+fn temp_dir() -> PathBuf {
+	return std::env::temp_dir().join(format!("timetabletag-test-{}", uuid::Uuid::new_v4()));
+}
+
+mod in_memory {
+	use super::*;
+
+	#[test]
+	fn get_returns_inserted_game() {
+		let store = InMemoryGameStore::new();
+		let lobby = Lobby::create("test_1".to_string(), None);
+		let game_id = lobby.id;
+
+		store.insert(Game::Lobby(lobby)).unwrap();
+
+		assert_eq!(store.get(&game_id).unwrap().blocking_lock().id(), game_id);
+	}
+
+	#[test]
+	fn find_lobby_by_invite_code_works() {
+		let store = InMemoryGameStore::new();
+		let lobby = Lobby::create("test_1".to_string(), None);
+		let game_id = lobby.id;
+		let invite_code = lobby.invite_code.clone();
+
+		store.insert(Game::Lobby(lobby)).unwrap();
+
+		assert_eq!(store.find_lobby_by_invite_code(&invite_code).unwrap().blocking_lock().id(), game_id);
+		assert!(store.find_lobby_by_invite_code("not-a-code").is_none());
+	}
+
+	#[test]
+	fn find_lobby_by_invite_code_ignores_started_games() {
+		let store = InMemoryGameStore::new();
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let invite_code = lobby.invite_code.clone();
+
+		store.insert(Game::InProgress(lobby.start(lobby.host).unwrap())).unwrap();
+
+		assert!(store.find_lobby_by_invite_code(&invite_code).is_none());
+	}
+
+	#[test]
+	fn invite_code_stops_working_once_game_is_started() {
+		let store = InMemoryGameStore::new();
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let invite_code = lobby.invite_code.clone();
+		let host = lobby.host;
+
+		let game = store.insert(Game::Lobby(lobby)).unwrap();
+		let mut game = game.blocking_lock();
+		let in_progress_game = match &mut *game {
+			Game::Lobby(lobby) => lobby.start(host).unwrap(),
+			_ => panic!("game isn't a lobby"),
+		};
+		*game = Game::InProgress(in_progress_game);
+		store.persist(&game).unwrap();
+
+		assert!(store.find_lobby_by_invite_code(&invite_code).is_none());
+	}
+
+	#[test]
+	fn different_games_dont_block_each_other() {
+		let store = InMemoryGameStore::new();
+		let game_1 = store.insert(Game::Lobby(Lobby::create("test_1".to_string(), None))).unwrap();
+		let game_2 = store.insert(Game::Lobby(Lobby::create("test_2".to_string(), None))).unwrap();
+
+		let _game_1 = game_1.blocking_lock();
+
+		assert!(game_2.try_lock().is_ok());
+	}
+
+	#[test]
+	fn same_game_waits_for_lock() {
+		let store = InMemoryGameStore::new();
+		let game = store.insert(Game::Lobby(Lobby::create("test_1".to_string(), None))).unwrap();
+		let game_id = game.blocking_lock().id();
+
+		let locked = game.clone().blocking_lock_owned();
+		assert!(store.get(&game_id).unwrap().try_lock().is_err());
+
+		let waiting = std::thread::spawn(move || {
+			let mut game = store.get(&game_id).unwrap().blocking_lock_owned();
+			if let Game::Lobby(lobby) = &mut *game {
+				lobby.join("test_2".to_string()).unwrap();
+			}
+		});
+
+		std::thread::sleep(std::time::Duration::from_millis(50));
+		drop(locked);
+		waiting.join().unwrap();
+
+		let game = game.blocking_lock();
+		match &*game {
+			Game::Lobby(lobby) => assert_eq!(lobby.players.len(), 2),
+			_ => panic!("game isn't a lobby anymore"),
+		}
+	}
+
+	#[test]
+	fn find_session_works_for_players_that_joined_later() {
+		let store = InMemoryGameStore::new();
+		let lobby = Lobby::create("test_1".to_string(), None);
+		let game_id = lobby.id;
+		let host = lobby.host;
+		let host_token = lobby.players[0].session_token().unwrap().to_string();
+
+		let game = store.insert(Game::Lobby(lobby)).unwrap();
+		let mut game = game.blocking_lock();
+		let (player2, player2_token) = match &mut *game {
+			Game::Lobby(lobby) => {
+				let player2 = lobby.join("test_2".to_string()).unwrap();
+				(player2, lobby.players[1].session_token().unwrap().to_string())
+			},
+			_ => panic!("game isn't a lobby"),
+		};
+
+		assert!(store.find_session(&player2_token).is_none());
+		store.persist(&game).unwrap();
+
+		assert_eq!(store.find_session(&host_token), Some((game_id, Participant::Player(host))));
+		assert_eq!(store.find_session(&player2_token), Some((game_id, Participant::Player(player2))));
+		assert!(store.find_session("not-a-token").is_none());
+	}
+
+	#[test]
+	fn spectators_can_join_started_games() {
+		let store = InMemoryGameStore::new();
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let game_id = lobby.id;
+		let spectator_invite_code = lobby.spectators.invite_code.clone();
+
+		store.insert(Game::InProgress(lobby.start(lobby.host).unwrap())).unwrap();
+
+		let game = store.find_game_by_spectator_invite_code(&spectator_invite_code).unwrap();
+		let mut game = game.blocking_lock();
+		let spectator_id = game.spectators_mut().join("viewer".to_string());
+		let session_token = game.spectators().spectators[0].session_token().to_string();
+		store.persist(&game).unwrap();
+
+		assert_eq!(store.find_session(&session_token), Some((game_id, Participant::Spectator(spectator_id))));
+	}
+
+	#[test]
+	fn session_stops_working_after_leaving() {
+		let store = InMemoryGameStore::new();
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let player2 = lobby.join("test_2".to_string()).unwrap();
+		let player2_token = lobby.players[1].session_token().unwrap().to_string();
+
+		let game = store.insert(Game::Lobby(lobby)).unwrap();
+		assert!(store.find_session(&player2_token).is_some());
+
+		let mut game = game.blocking_lock();
+		match &mut *game {
+			Game::Lobby(lobby) => lobby.leave(player2).unwrap(),
+			_ => panic!("game isn't a lobby"),
+		}
+		store.persist(&game).unwrap();
+
+		assert!(store.find_session(&player2_token).is_none());
+	}
+
+	#[test]
+	fn game_counts_follow_the_phase() {
+		let store = InMemoryGameStore::new();
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let game_id = lobby.id;
+		let host = lobby.host;
+		store.insert(Game::Lobby(Lobby::create("test_4".to_string(), None))).unwrap();
+
+		let game = store.insert(Game::Lobby(lobby)).unwrap();
+		assert_eq!(store.game_counts(), GameCounts { lobby: 2, in_progress: 0, finished: 0 });
+
+		let mut game = game.blocking_lock();
+		let in_progress_game = match &mut *game {
+			Game::Lobby(lobby) => lobby.start(host).unwrap(),
+			_ => panic!("game isn't a lobby"),
+		};
+		*game = Game::InProgress(in_progress_game);
+		store.persist(&game).unwrap();
+		store.persist(&game).unwrap();
+		assert_eq!(store.game_counts(), GameCounts { lobby: 1, in_progress: 1, finished: 0 });
+
+		store.remove(&[game_id]).unwrap();
+		assert_eq!(store.game_counts(), GameCounts { lobby: 1, in_progress: 0, finished: 0 });
+	}
+
+	#[test]
+	fn removing_a_game_only_forgets_its_own_codes_and_sessions() {
+		let store = InMemoryGameStore::new();
+		let removed = Lobby::create("test_1".to_string(), None);
+		let removed_token = removed.players[0].session_token().unwrap().to_string();
+		let removed_invite_code = removed.invite_code.clone();
+		let removed_id = removed.id;
+		let kept = Lobby::create("test_2".to_string(), None);
+		let kept_token = kept.players[0].session_token().unwrap().to_string();
+		let kept_invite_code = kept.invite_code.clone();
+
+		store.insert(Game::Lobby(removed)).unwrap();
+		store.insert(Game::Lobby(kept)).unwrap();
+		store.remove(&[removed_id]).unwrap();
+
+		assert!(store.find_session(&removed_token).is_none());
+		assert!(store.find_lobby_by_invite_code(&removed_invite_code).is_none());
+		assert!(store.find_session(&kept_token).is_some());
+		assert!(store.find_lobby_by_invite_code(&kept_invite_code).is_some());
+	}
+}
+
+mod file {
+	use super::*;
+
+	#[test]
+	fn games_survive_reopening() {
+		let dir = temp_dir();
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let game_id = lobby.id;
+
+		{
+			let store = FileGameStore::open(&dir).unwrap();
+			store.insert(Game::Lobby(lobby)).unwrap();
+		}
+
+		let store = FileGameStore::open(&dir).unwrap();
+		match &*store.get(&game_id).unwrap().blocking_lock() {
+			Game::Lobby(lobby) => assert_eq!(lobby.players.len(), 2),
+			_ => panic!("game isn't a lobby anymore"),
+		}
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+
+	#[test]
+	fn persisted_changes_survive_reopening() {
+		let dir = temp_dir();
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let game_id = lobby.id;
+		let host = lobby.host;
+
+		let in_progress_game = {
+			let store = FileGameStore::open(&dir).unwrap();
+			store.insert(Game::Lobby(lobby)).unwrap();
+
+			let game = store.get(&game_id).unwrap();
+			let mut game = game.blocking_lock();
+			let in_progress_game = match &mut *game {
+				Game::Lobby(lobby) => lobby.start(host).unwrap(),
+				_ => panic!("game isn't a lobby"),
+			};
+			*game = Game::InProgress(in_progress_game.clone());
+			store.persist(&game).unwrap();
+			in_progress_game
+		};
+
+		let store = FileGameStore::open(&dir).unwrap();
+		match &*store.get(&game_id).unwrap().blocking_lock() {
+			Game::InProgress(game) => {
+				assert_eq!(game.runner, in_progress_game.runner);
+				assert_eq!(game.destination, in_progress_game.destination);
+				assert_eq!(game.players, in_progress_game.players);
+				assert_eq!(game.timetable_card_stack, in_progress_game.timetable_card_stack);
+				assert_eq!(game.event_card_stack, in_progress_game.event_card_stack);
+				assert_eq!(game.rng, in_progress_game.rng);
+			},
+			_ => panic!("game isn't in progress"),
+		}
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+
+	#[test]
+	fn sessions_survive_reopening() {
+		let dir = temp_dir();
+		let lobby = Lobby::create("test_1".to_string(), None);
+		let game_id = lobby.id;
+		let host = lobby.host;
+		let host_token = lobby.players[0].session_token().unwrap().to_string();
+
+		{
+			let store = FileGameStore::open(&dir).unwrap();
+			store.insert(Game::Lobby(lobby)).unwrap();
+		}
+
+		let store = FileGameStore::open(&dir).unwrap();
+		assert_eq!(store.find_session(&host_token), Some((game_id, Participant::Player(host))));
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+
+	#[test]
+	fn cut_off_log_line_gets_skipped() {
+		let dir = temp_dir();
+		let lobby = Lobby::create("test_1".to_string(), None);
+		let game_id = lobby.id;
+
+		{
+			let store = FileGameStore::open(&dir).unwrap();
+			store.insert(Game::Lobby(lobby)).unwrap();
+		}
+
+		let mut log = std::fs::OpenOptions::new().append(true).open(dir.join("games.log")).unwrap();
+		std::io::Write::write_all(&mut log, b"{\"Lobby\":{\"id\":").unwrap();
+
+		let store = FileGameStore::open(&dir).unwrap();
+		assert!(store.get(&game_id).is_some());
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+
+	#[test]
+	fn compact_empties_log() {
+		let dir = temp_dir();
+		let lobby = Lobby::create("test_1".to_string(), None);
+		let game_id = lobby.id;
+
+		let store = FileGameStore::open(&dir).unwrap();
+		store.insert(Game::Lobby(lobby)).unwrap();
+		store.wait_for_writes();
+		assert!(std::fs::metadata(dir.join("games.log")).unwrap().len() > 0);
+
+		store.compact().unwrap();
+		assert_eq!(std::fs::metadata(dir.join("games.log")).unwrap().len(), 0);
+
+		let store = FileGameStore::open(&dir).unwrap();
+		assert!(store.get(&game_id).is_some());
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+
+	#[test]
+	fn flush_writes_persisted_changes_to_snapshot() {
+		let dir = temp_dir();
+		let lobby = Lobby::create("test_1".to_string(), None);
+		let game_id = lobby.id;
+
+		{
+			let store = FileGameStore::open(&dir).unwrap();
+			let game = store.insert(Game::Lobby(lobby)).unwrap();
+			let mut game = game.blocking_lock();
+			if let Game::Lobby(lobby) = &mut *game {
+				lobby.join("test_2".to_string()).unwrap();
+			}
+			store.persist(&game).unwrap();
+
+			store.flush().unwrap();
+			assert_eq!(std::fs::metadata(dir.join("games.log")).unwrap().len(), 0);
+		}
+
+		let snapshot: Vec<Game> = serde_json::from_str(&std::fs::read_to_string(dir.join("snapshot.json")).unwrap()).unwrap();
+		assert_eq!(snapshot.len(), 1);
+		assert_eq!(snapshot[0].id(), game_id);
+		assert_eq!(snapshot[0].players().len(), 2);
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+}
+
+mod sweeper {
+	use super::*;
+	use crate::game::{Team, WinCondition};
+	use crate::game::finished_game::FinishedGame;
+	use crate::game_store::sweeper::{sweep, ExpirySettings};
+
+	const NOW: u64 = 1_000_000_000;
+
+	fn lobby_last_active_at(last_activity: u64) -> Lobby {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		lobby.last_activity = last_activity;
+		return lobby;
+	}
+
+	fn finished_game_last_active_at(last_activity: u64) -> FinishedGame {
+		let mut lobby = Lobby::create("test_1".to_string(), None);
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let in_progress_game = lobby.start(lobby.host).unwrap();
+		let mut finished_game = FinishedGame::from_in_progress_game(&in_progress_game, Team::Runner, WinCondition::GotToDestination);
+		finished_game.last_activity = last_activity;
+		return finished_game;
+	}
+
+	#[test]
+	fn expired_lobby_gets_removed() {
+		let store = InMemoryGameStore::new();
+		let settings = ExpirySettings::default();
+		let lobby = lobby_last_active_at(NOW - settings.lobby_ttl * 1000);
+		let game_id = lobby.id;
+		let invite_code = lobby.invite_code.clone();
+		let host_token = lobby.players[0].session_token().unwrap().to_string();
+
+		store.insert(Game::Lobby(lobby)).unwrap();
+
+		assert_eq!(sweep(&store, &settings, NOW), vec![game_id]);
+		assert!(store.get(&game_id).is_none());
+		assert!(store.game_ids().is_empty());
+		assert!(store.find_lobby_by_invite_code(&invite_code).is_none());
+		assert!(store.find_session(&host_token).is_none());
+	}
+
+	#[test]
+	fn recently_active_games_are_kept() {
+		let store = InMemoryGameStore::new();
+		let settings = ExpirySettings::default();
+		let lobby = lobby_last_active_at(NOW - settings.lobby_ttl * 1000 + 1);
+		let game_id = lobby.id;
+
+		store.insert(Game::Lobby(lobby)).unwrap();
+
+		assert!(sweep(&store, &settings, NOW).is_empty());
+		assert!(store.get(&game_id).is_some());
+	}
+
+	#[test]
+	fn huge_ttl_doesnt_overflow() {
+		let settings = ExpirySettings { lobby_ttl: u64::MAX, ..Default::default() };
+
+		assert!(!settings.is_expired(&Game::Lobby(lobby_last_active_at(NOW)), u64::MAX - 1));
+	}
+
+	#[test]
+	fn ttl_depends_on_phase() {
+		let store = InMemoryGameStore::new();
+		let settings = ExpirySettings {
+			lobby_ttl: 10,
+			in_progress_ttl: 100,
+			finished_ttl: 1,
+			archive_path: None,
+		};
+		let lobby = lobby_last_active_at(NOW - 50_000);
+		let lobby_id = lobby.id;
+		let mut in_progress_lobby = lobby_last_active_at(0);
+		let _ = in_progress_lobby.join("test_2".to_string());
+		let _ = in_progress_lobby.join("test_3".to_string());
+		let mut in_progress_game = in_progress_lobby.start(in_progress_lobby.host).unwrap();
+		in_progress_game.last_activity = NOW - 50_000;
+		let in_progress_id = in_progress_game.id;
+		let finished_game = finished_game_last_active_at(NOW - 50_000);
+		let finished_id = finished_game.id;
+
+		store.insert(Game::Lobby(lobby)).unwrap();
+		store.insert(Game::InProgress(in_progress_game)).unwrap();
+		store.insert(Game::Finished(finished_game)).unwrap();
+
+		let mut removed = sweep(&store, &settings, NOW);
+		removed.sort();
+		let mut expected = vec![lobby_id, finished_id];
+		expected.sort();
+		assert_eq!(removed, expected);
+		assert!(store.get(&in_progress_id).is_some());
+	}
+
+	#[test]
+	fn locked_games_are_skipped() {
+		let store = InMemoryGameStore::new();
+		let lobby = lobby_last_active_at(1);
+		let game_id = lobby.id;
+
+		let game = store.insert(Game::Lobby(lobby)).unwrap();
+		let _guard = game.blocking_lock();
+
+		assert!(sweep(&store, &ExpirySettings::default(), NOW).is_empty());
+		assert!(store.get(&game_id).is_some());
+	}
+
+	#[test]
+	fn games_without_last_activity_start_their_ttl() {
+		let store = InMemoryGameStore::new();
+		let settings = ExpirySettings::default();
+		let lobby = lobby_last_active_at(0);
+		let game_id = lobby.id;
+
+		store.insert(Game::Lobby(lobby)).unwrap();
+
+		assert!(sweep(&store, &settings, NOW).is_empty());
+		assert_eq!(store.get(&game_id).unwrap().blocking_lock().last_activity(), NOW);
+		assert_eq!(sweep(&store, &settings, NOW + settings.lobby_ttl * 1000), vec![game_id]);
+	}
+
+	#[test]
+	fn persisting_a_removed_game_doesnt_bring_it_back() {
+		let store = InMemoryGameStore::new();
+		let lobby = lobby_last_active_at(1);
+		let host_token = lobby.players[0].session_token().unwrap().to_string();
+
+		let game = store.insert(Game::Lobby(lobby)).unwrap();
+		sweep(&store, &ExpirySettings::default(), NOW);
+		store.persist(&game.blocking_lock()).unwrap();
+
+		assert!(store.find_session(&host_token).is_none());
+	}
+
+	#[test]
+	fn finished_games_get_archived() {
+		let dir = temp_dir();
+		std::fs::create_dir_all(&dir).unwrap();
+		let store = InMemoryGameStore::new();
+		let settings = ExpirySettings {
+			archive_path: Some(dir.clone()),
+			..Default::default()
+		};
+		let finished_game = finished_game_last_active_at(1);
+		let game_id = finished_game.id;
+		let lobby = lobby_last_active_at(1);
+
+		store.insert(Game::Finished(finished_game.clone())).unwrap();
+		store.insert(Game::Lobby(lobby)).unwrap();
+
+		assert_eq!(sweep(&store, &settings, NOW).len(), 2);
+
+		let archived: FinishedGame = serde_json::from_str(&std::fs::read_to_string(dir.join(format!("{game_id}.json"))).unwrap()).unwrap();
+		assert_eq!(archived, finished_game);
+		//only finished games get archived
+		assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+
+	#[test]
+	fn expired_games_get_removed_from_the_snapshot_together() {
+		let dir = temp_dir();
+		let store = FileGameStore::open(&dir).unwrap();
+		let expired: Vec<GameId> = (0..3).map(|_| store.insert(Game::Lobby(lobby_last_active_at(1))).unwrap().blocking_lock().id()).collect();
+		let lobby = lobby_last_active_at(NOW);
+		let game_id = lobby.id;
+		store.insert(Game::Lobby(lobby)).unwrap();
+
+		let mut removed = sweep(&store, &ExpirySettings::default(), NOW);
+		removed.sort();
+		let mut expected = expired.clone();
+		expected.sort();
+		assert_eq!(removed, expected);
+
+		store.wait_for_writes();
+		let snapshot: Vec<Game> = serde_json::from_str(&std::fs::read_to_string(dir.join("snapshot.json")).unwrap()).unwrap();
+		assert_eq!(snapshot.iter().map(|x| x.id()).collect::<Vec<_>>(), vec![game_id]);
+		assert_eq!(std::fs::metadata(dir.join("games.log")).unwrap().len(), 0);
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+
+	#[test]
+	fn removed_games_stay_gone_after_reopening() {
+		let dir = temp_dir();
+		let expired_lobby = lobby_last_active_at(1);
+		let expired_id = expired_lobby.id;
+		let lobby = lobby_last_active_at(NOW);
+		let game_id = lobby.id;
+
+		{
+			let store = FileGameStore::open(&dir).unwrap();
+			store.insert(Game::Lobby(expired_lobby)).unwrap();
+			store.insert(Game::Lobby(lobby)).unwrap();
+			assert_eq!(sweep(&store, &ExpirySettings::default(), NOW), vec![expired_id]);
+		}
+
+		let store = FileGameStore::open(&dir).unwrap();
+		assert!(store.get(&expired_id).is_none());
+		assert!(store.get(&game_id).is_some());
+
+		let _ = std::fs::remove_dir_all(dir);
+	}
+}
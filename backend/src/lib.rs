@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod test;
+
+mod game;
+mod game_store;
+mod timetable_card;
+mod event_card;
+pub mod location;
+pub mod rest_api;
+pub mod config;
+mod metrics;
+mod logging;
+mod powerup;
+
+#[derive(Debug, Clone)]
+pub enum CustomError {
+  LobbyFull,
+  DisplayNameTaken,
+  LobbyNotFullEnough,
+  NotEveryoneReady,
+  InvalidGameState,
+  ActionNotAllowed,
+  NotYourTurn,
+  InvalidNextLocation,
+  MissingTimetableCard,
+  AlreadyMoved,
+  NotEnoughCoins,
+  EventCardNoLocationSent,
+  EventCardAlreadyBought,
+  NotAnEventField,
+  EventCardStackEmpty,
+  EventCardNotOnYourHand,
+  YoureCurrentlyHuntedByMenForSport,
+  YouMustGoToGermanyOrFrance,
+  YouMustGoNorth,
+  YouAreCurrentlyInRatMode,
+  YouMustLeaveTheCountryImmediately,
+  ValidMovePossible,
+  ThrewTooManyTimetableCardsAway,
+  GameNotFound,
+  ChallengeNotFound,
+  InviteCodeNotFound,
+  MissingSessionToken,
+  InvalidSessionToken,
+  UnknownPlayer,
+  ServerBusy,
+  InvalidRequestBody(String),
+  InvalidLocation(String),
+  InvalidTimetableCard(String),
+  InvalidEventCard(String),
+  InvalidPowerup(String),
+  InvalidSettings(String),
+}
+
+impl std::fmt::Display for CustomError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    return match self {
+      CustomError::LobbyFull => write!(f, "lobby is already full, the host decides how many players can play at a time"),
+      CustomError::DisplayNameTaken => write!(f, "someone in this lobby already uses this name"),
+      CustomError::LobbyNotFullEnough => write!(f, "not enough players to start game, the host decides how many players are required to play"),
+      CustomError::NotEveryoneReady => write!(f, "not everyone is ready yet, the host wants everyone to be ready before the game starts"),
+      CustomError::InvalidGameState => write!(f, "the current game isn't in a state where this operation is allowed"),
+      CustomError::ActionNotAllowed => write!(f, "you are not allowed to do what you just tried to do"),
+      CustomError::NotYourTurn => write!(f, "it's not your turn"),
+      CustomError::InvalidNextLocation => write!(f, "you actually can't get to the chosen next location"),
+      CustomError::MissingTimetableCard => write!(f, "you don't have the card you're trying to use"),
+      CustomError::AlreadyMoved => write!(f, "you already moved in your current turn"),
+      CustomError::NotEnoughCoins => write!(f, "you don't have enough coins"),
+      CustomError::EventCardNoLocationSent => write!(f, "you need to send a new location before buying an event card"),
+      CustomError::EventCardAlreadyBought => write!(f, "you already bought an event card in this turn"),
+      CustomError::NotAnEventField => write!(f, "you can only buy an event card when you're on an event spot"),
+      CustomError::EventCardStackEmpty => write!(f, "there aren't any event cards in the stack anymore. Congratulations!"),
+      CustomError::EventCardNotOnYourHand => write!(f, "you don't have the event card you're trying to play on your hand."),
+      CustomError::YoureCurrentlyHuntedByMenForSport => write!(f, "you're currently hunted by men for sport. You are very scared and must use your fastest method of transport"),
+      CustomError::YouMustGoToGermanyOrFrance => write!(f, "what is luxembourg if not germany-france? You must go to either germany or france in your current turn!"),
+      CustomError::YouMustGoNorth => write!(f, "you're currently navigating on cardinal directions and vibes and thus must go north!"),
+      CustomError::YouAreCurrentlyInRatMode => write!(f, "you're currently in rat mode! You have to use the slowest possible transport method."),
+      CustomError::YouMustLeaveTheCountryImmediately => write!(f, "you must leave the country immediately!"),
+      CustomError::ValidMovePossible => write!(f, "you still have at least one valid move"),
+      CustomError::ThrewTooManyTimetableCardsAway => write!(f, "you are only allowed to throw up to two timetable cards away"),
+      CustomError::GameNotFound => write!(f, "there is no game with this id"),
+      CustomError::ChallengeNotFound => write!(f, "there is no challenge with this id in the game"),
+      CustomError::InviteCodeNotFound => write!(f, "there is no open lobby with this invite code"),
+      CustomError::MissingSessionToken => write!(f, "you need to send your session token in the Authorization header"),
+      CustomError::InvalidSessionToken => write!(f, "this session token doesn't belong to any player"),
+      CustomError::UnknownPlayer => write!(f, "there is no player with this id in the game"),
+      CustomError::ServerBusy => write!(f, "the server is busy right now, please try again"),
+      CustomError::InvalidRequestBody(reason) => write!(f, "the request couldn't be read: {reason}"),
+      CustomError::InvalidLocation(value) => write!(f, "{value} not a valid Location ID"),
+      CustomError::InvalidTimetableCard(value) => write!(f, "{value} not a valid TimetableCard ID"),
+      CustomError::InvalidEventCard(value) => write!(f, "{value} not a valid EventCard ID"),
+      CustomError::InvalidPowerup(value) => write!(f, "{value} is not a valid powerup"),
+      CustomError::InvalidSettings(reason) => write!(f, "these settings don't work: {reason}"),
+    }
+  }
+}
+
+impl std::error::Error for CustomError {
+
+}
+
+impl CustomError {
+  // Stable identifier clients can match on, the message is only meant for humans
+  pub fn error_id(&self) -> &'static str {
+    return match self {
+      CustomError::LobbyFull => "lobby_full",
+      CustomError::DisplayNameTaken => "display_name_taken",
+      CustomError::LobbyNotFullEnough => "lobby_not_full_enough",
+      CustomError::NotEveryoneReady => "not_everyone_ready",
+      CustomError::InvalidGameState => "invalid_game_state",
+      CustomError::ActionNotAllowed => "action_not_allowed",
+      CustomError::NotYourTurn => "not_your_turn",
+      CustomError::InvalidNextLocation => "invalid_next_location",
+      CustomError::MissingTimetableCard => "missing_timetable_card",
+      CustomError::AlreadyMoved => "already_moved",
+      CustomError::NotEnoughCoins => "not_enough_coins",
+      CustomError::EventCardNoLocationSent => "event_card_no_location_sent",
+      CustomError::EventCardAlreadyBought => "event_card_already_bought",
+      CustomError::NotAnEventField => "not_an_event_field",
+      CustomError::EventCardStackEmpty => "event_card_stack_empty",
+      CustomError::EventCardNotOnYourHand => "event_card_not_on_your_hand",
+      CustomError::YoureCurrentlyHuntedByMenForSport => "youre_currently_hunted_by_men_for_sport",
+      CustomError::YouMustGoToGermanyOrFrance => "you_must_go_to_germany_or_france",
+      CustomError::YouMustGoNorth => "you_must_go_north",
+      CustomError::YouAreCurrentlyInRatMode => "you_are_currently_in_rat_mode",
+      CustomError::YouMustLeaveTheCountryImmediately => "you_must_leave_the_country_immediately",
+      CustomError::ValidMovePossible => "valid_move_possible",
+      CustomError::ThrewTooManyTimetableCardsAway => "threw_too_many_timetable_cards_away",
+      CustomError::GameNotFound => "game_not_found",
+      CustomError::ChallengeNotFound => "challenge_not_found",
+      CustomError::InviteCodeNotFound => "invite_code_not_found",
+      CustomError::MissingSessionToken => "missing_session_token",
+      CustomError::InvalidSessionToken => "invalid_session_token",
+      CustomError::UnknownPlayer => "unknown_player",
+      CustomError::ServerBusy => "server_busy",
+      CustomError::InvalidRequestBody(_) => "invalid_request_body",
+      CustomError::InvalidLocation(_) => "invalid_location",
+      CustomError::InvalidTimetableCard(_) => "invalid_timetable_card",
+      CustomError::InvalidEventCard(_) => "invalid_event_card",
+      CustomError::InvalidPowerup(_) => "invalid_powerup",
+      CustomError::InvalidSettings(_) => "invalid_settings",
+    };
+  }
+}
\ No newline at end of file
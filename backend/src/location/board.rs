@@ -0,0 +1,162 @@
+use super::*;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::sync::OnceLock;
+
+// The board every server starts with unless BOARD_PATH points to another one
+const DEFAULT_BOARD: &str = include_str!("../../data/board.json");
+
+// Locations the game rules refer to by name, every board needs to have them and the destinations
+pub const REQUIRED_LOCATIONS: [Location; 2] = [Location::Nancy, Location::Ljubljana];
+
+static BOARD: OnceLock<Board> = OnceLock::new();
+
+// The board file as it is written on disk, see data/board.json
+#[derive(Debug, Clone, serde::Deserialize)]
+struct BoardFile {
+	nodes: Vec<NodeFile>,
+	edges: Vec<EdgeFile>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct NodeFile {
+	id: String,
+	country: String,
+	#[serde(default)]
+	coin_field: bool,
+	#[serde(default)]
+	event_field: bool,
+	#[serde(default)]
+	coastal: bool,
+	coordinates: Coordinates,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct EdgeFile {
+	from: String,
+	to: String,
+	#[serde(rename = "type")]
+	connection_type: ConnectionType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionType {
+	LowSpeed,
+	HighSpeed,
+	Plane,
+	// Only goes one way, used by CardinalDirectionsAndVibes
+	North,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Coordinates {
+	pub lat: f64,
+	pub lon: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+	pub country: Country,
+	pub coin_field: bool,
+	pub event_field: bool,
+	pub coastal: bool,
+	pub coordinates: Coordinates,
+	pub low_speed_connections: Vec<Location>,
+	pub high_speed_connections: Vec<Location>,
+	pub plane_connections: Vec<Location>,
+	pub north_connections: Vec<Location>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Board {
+	pub nodes: BTreeMap<Location, Node>,
+}
+
+impl Board {
+	// Low speed, high speed and plane edges go both ways, north edges only from `from` to `to`.
+	// Node ids have to be variants of Location, a board can leave locations out but can't add new ones
+	pub fn parse(json: &str) -> Result<Board, Box<dyn Error>> {
+		let board_file: BoardFile = serde_json::from_str(json)?;
+
+		let mut nodes: BTreeMap<Location, Node> = BTreeMap::new();
+		for node in board_file.nodes {
+			let location = Location::try_from_id(&node.id).ok_or_else(|| format!("unknown location {}, boards can only use the locations built into the server", node.id))?;
+			let country = Country::try_from(node.country.as_str())?;
+
+			if nodes.contains_key(&location) {
+				return Err(format!("location {location} is defined twice").into());
+			}
+
+			nodes.insert(location, Node {
+				country,
+				coin_field: node.coin_field,
+				event_field: node.event_field,
+				coastal: node.coastal,
+				coordinates: node.coordinates,
+				low_speed_connections: Vec::new(),
+				high_speed_connections: Vec::new(),
+				plane_connections: Vec::new(),
+				north_connections: Vec::new(),
+			});
+		}
+
+		for edge in board_file.edges {
+			let from = Location::try_from_id(&edge.from).filter(|x| nodes.contains_key(x)).ok_or_else(|| format!("edge from unknown location {}", edge.from))?;
+			let to = Location::try_from_id(&edge.to).filter(|x| nodes.contains_key(x)).ok_or_else(|| format!("edge to unknown location {}", edge.to))?;
+
+			match edge.connection_type {
+				ConnectionType::LowSpeed => {
+					nodes.get_mut(&from).unwrap().low_speed_connections.push(to);
+					nodes.get_mut(&to).unwrap().low_speed_connections.push(from);
+				},
+				ConnectionType::HighSpeed => {
+					nodes.get_mut(&from).unwrap().high_speed_connections.push(to);
+					nodes.get_mut(&to).unwrap().high_speed_connections.push(from);
+				},
+				ConnectionType::Plane => {
+					nodes.get_mut(&from).unwrap().plane_connections.push(to);
+					nodes.get_mut(&to).unwrap().plane_connections.push(from);
+				},
+				ConnectionType::North => {
+					nodes.get_mut(&from).unwrap().north_connections.push(to);
+				},
+			}
+		}
+
+		for location in REQUIRED_LOCATIONS {
+			if !nodes.contains_key(&location) {
+				return Err(format!("location {location} is missing, the game rules need it").into());
+			}
+		}
+
+		//the default settings hand these out, so a board without one of them couldn't start a game
+		for location in DESTINATIONS {
+			if !nodes.contains_key(&location) {
+				return Err(format!("destination {location} is missing").into());
+			}
+		}
+
+		return Ok(Board { nodes });
+	}
+
+	pub fn node(&self, location: &Location) -> Result<&Node, crate::CustomError> {
+		return self.nodes.get(location).ok_or_else(|| crate::CustomError::InvalidLocation(location.to_string()));
+	}
+}
+
+// The board the game is played on, the default board is used if no other board got loaded before
+pub fn board() -> &'static Board {
+	return BOARD.get_or_init(|| Board::parse(DEFAULT_BOARD).expect("default board is invalid"));
+}
+
+// Replaces the default board, has to happen before the board gets used for the first time
+pub fn load_board(path: &std::path::Path) -> Result<(), Box<dyn Error>> {
+	let board = Board::parse(&std::fs::read_to_string(path)?)?;
+
+	if BOARD.set(board).is_err() {
+		return Err("board is already in use".into());
+	}
+
+	return Ok(());
+}
@@ -0,0 +1,58 @@
+use actix_web::{get, HttpResponse, Responder};
+use serde::Serialize;
+use super::*;
+use board::{ConnectionType, Coordinates};
+
+#[derive(Debug, Clone, Serialize)]
+struct BoardView {
+	nodes: Vec<NodeView>,
+	edges: Vec<EdgeView>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct NodeView {
+	id: String,
+	country: String,
+	coin_field: bool,
+	event_field: bool,
+	coastal: bool,
+	coordinates: Coordinates,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct EdgeView {
+	from: String,
+	to: String,
+	#[serde(rename = "type")]
+	connection_type: ConnectionType,
+}
+
+// The board the server plays on, in the same format as data/board.json
+#[get("/api/v1/board")]
+pub async fn get_board() -> impl Responder {
+	let board = board();
+
+	let nodes = board.nodes.iter().map(|(location, node)| NodeView {
+		id: location.to_string(),
+		country: node.country.to_string(),
+		coin_field: node.coin_field,
+		event_field: node.event_field,
+		coastal: node.coastal,
+		coordinates: node.coordinates,
+	}).collect();
+
+	let mut edges: Vec<EdgeView> = Vec::new();
+	for (location, node) in &board.nodes {
+		//connections that go both ways only get listed once
+		for (connection_type, connections) in [(ConnectionType::LowSpeed, &node.low_speed_connections), (ConnectionType::HighSpeed, &node.high_speed_connections), (ConnectionType::Plane, &node.plane_connections)] {
+			for connection in connections.iter().filter(|x| *x > location) {
+				edges.push(EdgeView { from: location.to_string(), to: connection.to_string(), connection_type });
+			}
+		}
+		for connection in &node.north_connections {
+			edges.push(EdgeView { from: location.to_string(), to: connection.to_string(), connection_type: ConnectionType::North });
+		}
+	}
+
+	return HttpResponse::Ok().body(serde_json::to_string(&BoardView { nodes, edges }).unwrap());
+}
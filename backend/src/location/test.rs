@@ -1,4 +1,5 @@
 use super::*;
+use crate::location::board::Board;
 #[test]
 fn all_low_speed_connections_fit() {
 	for loc in Location::get_iter() {
@@ -57,4 +58,176 @@ fn all_north_connections_are_unique() {
 			assert!(!connection.get_north_connections().contains(&loc));
 		}
 	}
-}
\ No newline at end of file
+}
+#[test]
+fn every_location_round_trips_through_its_id() {
+	for loc in Location::get_iter() {
+		assert_eq!(Location::try_from(loc.to_string()).unwrap(), loc);
+	}
+}
+
+#[test]
+fn ids_are_snake_case() {
+	assert_eq!(Location::CharlevilleMezieres.to_string(), "charleville_mezieres");
+	assert_eq!(Location::SHertogenbosch.to_string(), "s_hertogenbosch");
+	assert_eq!(Location::try_from("banja_luka").unwrap(), Location::BanjaLuka);
+}
+
+#[test]
+fn invalid_ids_get_rejected() {
+	assert!(Location::try_from("Paris").is_err());
+	assert!(Location::try_from("banjaluka").is_err());
+	assert!(Location::try_from("atlantis").is_err());
+	assert!(Location::try_from("").is_err());
+}
+
+#[test]
+fn default_board_contains_every_location() {
+	assert_eq!(Location::get_iter().count(), 120);
+	assert_eq!(Country::try_from(Location::Merlischachen).unwrap(), Country::Switzerland);
+	assert!(Location::Dublin.get_plane_connections().contains(&Location::Paris));
+}
+
+const REQUIRED_NODES: &str = r#"
+	{"id": "nancy", "country": "france", "coordinates": {"lat": 48.69, "lon": 6.18}},
+	{"id": "dublin", "country": "ireland", "coordinates": {"lat": 53.35, "lon": -6.26}},
+	{"id": "copenhagen", "country": "denmark", "coordinates": {"lat": 55.68, "lon": 12.57}},
+	{"id": "vienna", "country": "austria", "coordinates": {"lat": 48.21, "lon": 16.37}},
+	{"id": "rome", "country": "italy", "coordinates": {"lat": 41.90, "lon": 12.50}},
+	{"id": "madrid", "country": "spain", "coordinates": {"lat": 40.42, "lon": -3.70}},
+	{"id": "ljubljana", "country": "slovenia", "coordinates": {"lat": 46.06, "lon": 14.51}}
+"#;
+
+fn board_json(extra_nodes: &str, edges: &str) -> String {
+	return format!("{{\"nodes\": [{REQUIRED_NODES}{extra_nodes}], \"edges\": [{edges}]}}");
+}
+
+mod board {
+	use super::*;
+
+	#[test]
+	fn parses_minimal_board() {
+		let board = Board::parse(&board_json("", r#"{"from": "nancy", "to": "dublin", "type": "plane"}"#)).unwrap();
+
+		assert_eq!(board.nodes.len(), 7);
+		assert_eq!(board.node(&Location::Nancy).unwrap().plane_connections, vec![Location::Dublin]);
+		assert_eq!(board.node(&Location::Dublin).unwrap().plane_connections, vec![Location::Nancy]);
+		assert_eq!(board.node(&Location::Dublin).unwrap().country, Country::Ireland);
+		assert!(!board.node(&Location::Dublin).unwrap().coin_field);
+		assert!(board.node(&Location::Paris).is_err());
+	}
+
+	#[test]
+	fn north_edges_only_go_one_way() {
+		let board = Board::parse(&board_json("", r#"{"from": "rome", "to": "vienna", "type": "north"}"#)).unwrap();
+
+		assert_eq!(board.node(&Location::Rome).unwrap().north_connections, vec![Location::Vienna]);
+		assert!(board.node(&Location::Vienna).unwrap().north_connections.is_empty());
+	}
+
+	#[test]
+	fn unknown_location_gets_rejected() {
+		assert!(Board::parse(&board_json(r#",{"id": "atlantis", "country": "spain", "coordinates": {"lat": 0, "lon": 0}}"#, "")).is_err());
+	}
+
+	#[test]
+	fn unknown_country_gets_rejected() {
+		assert!(Board::parse(&board_json(r#",{"id": "paris", "country": "gaul", "coordinates": {"lat": 0, "lon": 0}}"#, "")).is_err());
+	}
+
+	#[test]
+	fn duplicate_location_gets_rejected() {
+		assert!(Board::parse(&board_json(r#",{"id": "nancy", "country": "france", "coordinates": {"lat": 0, "lon": 0}}"#, "")).is_err());
+	}
+
+	#[test]
+	fn edge_to_missing_location_gets_rejected() {
+		assert!(Board::parse(&board_json("", r#"{"from": "nancy", "to": "paris", "type": "low_speed"}"#)).is_err());
+	}
+
+	#[test]
+	fn missing_required_location_gets_rejected() {
+		let json = r#"{"nodes": [{"id": "nancy", "country": "france", "coordinates": {"lat": 48.69, "lon": 6.18}}], "edges": []}"#;
+		assert!(Board::parse(json).is_err());
+	}
+
+	#[test]
+	fn missing_destination_gets_rejected() {
+		let json = board_json("", "").replace(r#"{"id": "rome", "country": "italy", "coordinates": {"lat": 41.90, "lon": 12.50}},"#, "");
+		assert!(Board::parse(&json).unwrap_err().to_string().contains("destination rome"));
+	}
+}
+
+mod validate {
+	use super::*;
+	use crate::location::board::ConnectionType;
+	use crate::location::validate::BoardProblem;
+
+	const CHAIN: &str = r#"
+		{"from": "nancy", "to": "dublin", "type": "low_speed"},
+		{"from": "dublin", "to": "copenhagen", "type": "low_speed"},
+		{"from": "copenhagen", "to": "vienna", "type": "low_speed"},
+		{"from": "vienna", "to": "rome", "type": "low_speed"},
+		{"from": "rome", "to": "madrid", "type": "low_speed"},
+		{"from": "madrid", "to": "ljubljana", "type": "low_speed"}
+	"#;
+
+	fn problems(extra_nodes: &str, extra_edges: &str) -> Vec<BoardProblem> {
+		return Board::parse(&board_json(extra_nodes, &format!("{CHAIN}{extra_edges}"))).unwrap().validate();
+	}
+
+	#[test]
+	fn default_board_has_no_problems() {
+		let problems = board().validate();
+
+		assert!(problems.is_empty(), "{}", problems.iter().map(|x| x.to_string()).collect::<Vec<String>>().join("\n"));
+	}
+
+	#[test]
+	fn connected_board_has_no_problems() {
+		assert_eq!(problems("", ""), vec![]);
+	}
+
+	#[test]
+	fn finds_duplicate_edges() {
+		let problems = problems("", r#",{"from": "dublin", "to": "nancy", "type": "low_speed"}"#);
+
+		assert!(problems.contains(&BoardProblem::DuplicateEdge { from: Location::Nancy, to: Location::Dublin, connection_type: ConnectionType::LowSpeed }));
+	}
+
+	#[test]
+	fn finds_edges_to_itself() {
+		let problems = problems("", r#",{"from": "rome", "to": "rome", "type": "high_speed"}"#);
+
+		assert!(problems.contains(&BoardProblem::EdgeToItself { location: Location::Rome, connection_type: ConnectionType::HighSpeed }));
+	}
+
+	#[test]
+	fn finds_north_edges_without_transport() {
+		let problems = problems("", r#",{"from": "rome", "to": "copenhagen", "type": "north"}"#);
+
+		assert_eq!(problems, vec![BoardProblem::NorthEdgeWithoutTransport { from: Location::Rome, to: Location::Copenhagen }]);
+	}
+
+	#[test]
+	fn finds_isolated_locations_and_unreachable_destinations() {
+		let problems = problems(r#",{"id": "paris", "country": "france", "coordinates": {"lat": 48.86, "lon": 2.35}}"#, "");
+
+		assert!(problems.contains(&BoardProblem::IsolatedLocation(Location::Paris)));
+		assert!(problems.contains(&BoardProblem::UnreachableDestination { destination: Location::Rome, unreachable_from: vec![Location::Paris] }));
+	}
+
+	#[test]
+	fn finds_country_mismatches() {
+		let problems = problems(r#",{"id": "brussels", "country": "france", "coordinates": {"lat": 50.85, "lon": 4.35}}"#, r#",{"from": "brussels", "to": "nancy", "type": "low_speed"}"#);
+
+		assert_eq!(problems, vec![BoardProblem::CountryMismatch { location: Location::Brussels, expected: Country::Belgium, actual: Country::France }]);
+	}
+
+	#[test]
+	fn finds_coin_and_event_fields() {
+		let problems = problems(r#",{"id": "paris", "country": "france", "coin_field": true, "event_field": true, "coordinates": {"lat": 48.86, "lon": 2.35}}"#, r#",{"from": "paris", "to": "nancy", "type": "low_speed"}"#);
+
+		assert_eq!(problems, vec![BoardProblem::CoinAndEventField(Location::Paris)]);
+	}
+}
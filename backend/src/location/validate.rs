@@ -0,0 +1,120 @@
+use super::*;
+use board::{Board, ConnectionType};
+use std::collections::{BTreeSet, VecDeque};
+
+// Everything about a board that doesn't break loading it, but breaks playing on it.
+// There's no asymmetric low speed, high speed or plane edge: Board::parse connects both ways for every one of them
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardProblem {
+	DuplicateEdge { from: Location, to: Location, connection_type: ConnectionType },
+	EdgeToItself { location: Location, connection_type: ConnectionType },
+	NorthEdgeWithoutTransport { from: Location, to: Location },
+	IsolatedLocation(Location),
+	UnreachableDestination { destination: Location, unreachable_from: Vec<Location> },
+	CountryMismatch { location: Location, expected: Country, actual: Country },
+	CoinAndEventField(Location),
+}
+
+impl Display for BoardProblem {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			BoardProblem::DuplicateEdge { from, to, connection_type } => write!(f, "{connection_type:?} edge from {from} to {to} exists more than once"),
+			BoardProblem::EdgeToItself { location, connection_type } => write!(f, "{connection_type:?} edge from {location} goes to itself"),
+			BoardProblem::NorthEdgeWithoutTransport { from, to } => write!(f, "north edge from {from} to {to} can't be travelled with any timetable card"),
+			BoardProblem::IsolatedLocation(location) => write!(f, "{location} has no connections"),
+			BoardProblem::UnreachableDestination { destination, unreachable_from } => write!(f, "destination {destination} can't be reached from {}", unreachable_from.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(", ")),
+			BoardProblem::CountryMismatch { location, expected, actual } => write!(f, "{location} is in {actual}, but the game rules expect it in {expected}"),
+			BoardProblem::CoinAndEventField(location) => write!(f, "{location} is a coin field and an event field"),
+		}
+	}
+}
+
+// Locations the game rules expect in a specific country
+const RULE_COUNTRIES: [(Location, Country); 3] = [
+	(Location::Ljubljana, Country::Slovenia), //SloveniaAsATreat
+	(Location::Luxembourg, Country::Luxembourg), //LuxembourgIsGermanyFrance
+	(Location::Brussels, Country::Belgium), //LuxembourgIsGermanyFrance
+];
+
+impl Board {
+	// Checks the board for everything that would make a game on it unfair or impossible to finish
+	pub fn validate(&self) -> Vec<BoardProblem> {
+		let mut problems: Vec<BoardProblem> = Vec::new();
+
+		for (location, node) in &self.nodes {
+			for (connection_type, connections) in [(ConnectionType::LowSpeed, &node.low_speed_connections), (ConnectionType::HighSpeed, &node.high_speed_connections), (ConnectionType::Plane, &node.plane_connections), (ConnectionType::North, &node.north_connections)] {
+				let mut seen: BTreeSet<Location> = BTreeSet::new();
+
+				for connection in connections {
+					if connection == location {
+						problems.push(BoardProblem::EdgeToItself { location: *location, connection_type });
+					}
+
+					if !seen.insert(*connection) {
+						problems.push(BoardProblem::DuplicateEdge { from: *location, to: *connection, connection_type });
+					}
+
+					if connection_type == ConnectionType::North && !location.get_joker_connections_on(self).contains(connection) {
+						problems.push(BoardProblem::NorthEdgeWithoutTransport { from: *location, to: *connection });
+					}
+				}
+			}
+
+			if location.get_joker_connections_on(self).is_empty() {
+				problems.push(BoardProblem::IsolatedLocation(*location));
+			}
+
+			if node.coin_field && node.event_field {
+				problems.push(BoardProblem::CoinAndEventField(*location));
+			}
+		}
+
+		for destination in DESTINATIONS {
+			if !self.nodes.contains_key(&destination) {
+				continue;
+			}
+
+			let reachable = self.reachable_from(destination);
+			let unreachable_from: Vec<Location> = self.nodes.keys().filter(|x| !reachable.contains(x)).copied().collect();
+
+			if !unreachable_from.is_empty() {
+				problems.push(BoardProblem::UnreachableDestination { destination, unreachable_from });
+			}
+		}
+
+		for (location, expected) in RULE_COUNTRIES {
+			if let Some(node) = self.nodes.get(&location) {
+				if node.country != expected {
+					problems.push(BoardProblem::CountryMismatch { location, expected, actual: node.country });
+				}
+			}
+		}
+
+		return problems;
+	}
+
+	// Every location the given location can be reached from, going backwards along the edges
+	fn reachable_from(&self, target: Location) -> BTreeSet<Location> {
+		let mut reachable: BTreeSet<Location> = BTreeSet::from([target]);
+		let mut queue: VecDeque<Location> = VecDeque::from([target]);
+
+		while let Some(location) = queue.pop_front() {
+			for (other, _) in self.nodes.iter().filter(|(x, _)| x.get_joker_connections_on(self).contains(&location)) {
+				if reachable.insert(*other) {
+					queue.push_back(*other);
+				}
+			}
+		}
+
+		return reachable;
+	}
+}
+
+impl Location {
+	fn get_joker_connections_on(&self, board: &Board) -> Vec<Location> {
+		return match board.nodes.get(self) {
+			Some(node) => vec![node.low_speed_connections.clone(), node.high_speed_connections.clone(), node.plane_connections.clone()].into_iter().flatten().collect(),
+			None => Vec::new(),
+		};
+	}
+}
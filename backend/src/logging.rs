@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod test;
+
+use std::io::IsTerminal;
+use tracing::field::{display, Empty};
+use tracing::level_filters::LevelFilter;
+use tracing::Span;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::util::SubscriberInitExt;
+use crate::config::{Config, LogFormat, LogLevel};
+use crate::game::GameId;
+use crate::game::in_progress_game::Move;
+use crate::game::spectator::Participant;
+
+// Sends every tracing event of the server to stdout, in the format and up to the level of the config
+pub fn init(config: &Config) {
+	let level = match config.log_level {
+		LogLevel::Error => LevelFilter::ERROR,
+		LogLevel::Warn => LevelFilter::WARN,
+		LogLevel::Info => LevelFilter::INFO,
+		LogLevel::Debug => LevelFilter::DEBUG,
+	};
+
+	//only fails when a subscriber is already installed, which then keeps logging
+	let _ = match config.log_format {
+		//colors only end up as escape codes in docker logs and files
+		LogFormat::Text => tracing_subscriber::fmt().with_max_level(level).with_target(false).with_ansi(std::io::stdout().is_terminal()).finish().try_init(),
+		LogFormat::Json => json_subscriber(level, std::io::stdout).try_init(),
+	};
+}
+
+// One object per line with the fields of the event at the top level and the fields of every span it happened in under spans
+pub fn json_subscriber<W: for<'a> MakeWriter<'a> + Send + Sync + 'static>(level: LevelFilter, writer: W) -> impl tracing::Subscriber + Send + Sync {
+	return tracing_subscriber::fmt()
+		.json()
+		.flatten_event(true)
+		.with_current_span(false)
+		.with_target(false)
+		.with_max_level(level)
+		.with_writer(writer)
+		.finish();
+}
+
+// Every request runs in one of these. game_id and player_id or spectator_id are empty until
+// the session or the game of the request is known, see record_session and record_game
+pub fn request_span(request_id: &str, method: &str, route: &str) -> Span {
+	return tracing::info_span!("request", request_id, method, route, game_id = Empty, player_id = Empty, spectator_id = Empty);
+}
+
+// Only the public ids end up in the logs, never the session token they were found with
+pub fn record_session(game_id: &GameId, participant: &Participant) {
+	let span = Span::current();
+	span.record("game_id", display(game_id));
+	match participant {
+		Participant::Player(player_id) => span.record("player_id", display(player_id)),
+		Participant::Spectator(spectator_id) => span.record("spectator_id", display(spectator_id)),
+	};
+}
+
+// For requests without a session, everything the game logs is still tied to it
+pub fn record_game(game_id: &GameId) {
+	Span::current().record("game_id", display(game_id));
+}
+
+// The fields a move was sent with, the parsed ones are left out since they only repeat them
+pub fn move_span(game_id: &GameId, move_made: &Move) -> Span {
+	return tracing::info_span!(
+		"move",
+		game_id = %game_id,
+		player_id = %move_made.player_id,
+		next_location = move_made.next_location.as_deref(),
+		use_timetable_card = move_made.use_timetable_card.as_deref(),
+		buy_event_card = move_made.buy_event_card,
+		use_event_card = move_made.use_event_card.as_deref(),
+		buy_powerup = move_made.buy_powerup.as_deref(),
+		throw_timetable_cards_away = ?move_made.throw_timetable_cards_away,
+		finish_move = move_made.finish_move,
+	);
+}
+
+// Runs f with a json subscriber and returns every line it logged
+#[cfg(test)]
+pub fn capture(f: impl FnOnce()) -> Vec<serde_json::Value> {
+	let output = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+	let writer = {
+		let output = output.clone();
+		move || CapturedOutput(output.clone())
+	};
+
+	tracing::subscriber::with_default(json_subscriber(LevelFilter::DEBUG, writer), f);
+
+	let output = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+	return output.lines().map(|x| serde_json::from_str(x).unwrap()).collect();
+}
+
+#[cfg(test)]
+struct CapturedOutput(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+#[cfg(test)]
+impl std::io::Write for CapturedOutput {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.0.lock().unwrap().extend_from_slice(buf);
+		return Ok(buf.len());
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		return Ok(());
+	}
+}
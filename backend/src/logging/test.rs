@@ -0,0 +1,111 @@
+use super::*;
+use uuid::Uuid;
+
+// The fields of the span with the given name the only logged line happened in
+fn span_fields(lines: &[serde_json::Value], name: &str) -> serde_json::Value {
+	assert_eq!(lines.len(), 1);
+	return lines[0]["spans"].as_array().unwrap().iter().find(|x| x["name"] == name).cloned().unwrap();
+}
+
+mod request_span {
+	use super::*;
+
+	#[test]
+	fn has_request_id_method_and_route() {
+		let lines = capture(|| {
+			let _entered = request_span("some-request", "POST", "/api/v1/games/{game_id}/make_move").entered();
+			tracing::info!("something happened");
+		});
+
+		let span = span_fields(&lines, "request");
+		assert_eq!(span["request_id"], "some-request");
+		assert_eq!(span["method"], "POST");
+		assert_eq!(span["route"], "/api/v1/games/{game_id}/make_move");
+		assert!(span.get("game_id").is_none());
+		assert!(span.get("player_id").is_none());
+		assert_eq!(lines[0]["message"], "something happened");
+	}
+
+	#[test]
+	fn session_of_player_is_recorded() {
+		let game_id = Uuid::new_v4();
+		let player_id = Uuid::new_v4();
+
+		let lines = capture(|| {
+			let _entered = request_span("some-request", "GET", "/api/v1/games/{game_id}").entered();
+			record_session(&game_id, &Participant::Player(player_id));
+			tracing::info!("something happened");
+		});
+
+		let span = span_fields(&lines, "request");
+		assert_eq!(span["game_id"], game_id.to_string());
+		assert_eq!(span["player_id"], player_id.to_string());
+		assert!(span.get("spectator_id").is_none());
+	}
+
+	#[test]
+	fn session_of_spectator_is_recorded() {
+		let game_id = Uuid::new_v4();
+		let spectator_id = Uuid::new_v4();
+
+		let lines = capture(|| {
+			let _entered = request_span("some-request", "GET", "/api/v1/games/{game_id}").entered();
+			record_session(&game_id, &Participant::Spectator(spectator_id));
+			tracing::info!("something happened");
+		});
+
+		let span = span_fields(&lines, "request");
+		assert_eq!(span["game_id"], game_id.to_string());
+		assert_eq!(span["spectator_id"], spectator_id.to_string());
+		assert!(span.get("player_id").is_none());
+	}
+
+	#[test]
+	fn game_without_session_is_recorded() {
+		let game_id = Uuid::new_v4();
+
+		let lines = capture(|| {
+			let _entered = request_span("some-request", "GET", "/api/v1/games/{game_id}").entered();
+			record_game(&game_id);
+			tracing::info!("something happened");
+		});
+
+		let span = span_fields(&lines, "request");
+		assert_eq!(span["game_id"], game_id.to_string());
+		assert!(span.get("player_id").is_none());
+	}
+
+	#[test]
+	fn recording_outside_of_a_request_does_nothing() {
+		let lines = capture(|| {
+			record_session(&Uuid::new_v4(), &Participant::Player(Uuid::new_v4()));
+			record_game(&Uuid::new_v4());
+			tracing::info!("something happened");
+		});
+
+		assert_eq!(lines.len(), 1);
+		assert!(lines[0].get("spans").is_none_or(|x| x.as_array().unwrap().is_empty()));
+	}
+}
+
+mod level {
+	use super::*;
+
+	#[test]
+	fn events_below_the_level_are_dropped() {
+		let output = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+		let writer = {
+			let output = output.clone();
+			move || CapturedOutput(output.clone())
+		};
+
+		tracing::subscriber::with_default(json_subscriber(LevelFilter::WARN, writer), || {
+			tracing::info!("not important");
+			tracing::warn!("important");
+		});
+
+		let output = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+		assert!(!output.contains("not important"));
+		assert!(output.contains("important"));
+	}
+}
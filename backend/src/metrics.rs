@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod test;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use crate::game::{Game, Team, WinCondition};
+
+// Everything GET /metrics reports, collected from wherever it happens.
+// Games can't reach the AppState, so like the board this is global
+pub static METRICS: Metrics = Metrics::new();
+
+// Upper bounds in seconds, requests and lock waits are expected to take a few milliseconds
+const BUCKETS: [f64; 10] = [0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.5, 1.0];
+
+#[derive(Debug)]
+pub struct Metrics {
+	moves: AtomicU64,
+	//error_id -> count
+	rejected_moves: Mutex<BTreeMap<&'static str, u64>>,
+	//(win_condition, winning_team) -> count
+	finished_games: Mutex<BTreeMap<(&'static str, &'static str), u64>>,
+	//(method, route) -> durations
+	requests: Mutex<BTreeMap<(String, String), Histogram>>,
+	lock_waits: Mutex<Histogram>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+	//not cumulative, the last one counts everything above the last bucket
+	counts: [u64; BUCKETS.len() + 1],
+	sum: f64,
+}
+
+// Number of games in every phase at the time of the scrape, the game store keeps them up to date
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GameCounts {
+	pub lobby: usize,
+	pub in_progress: usize,
+	pub finished: usize,
+}
+
+// The phase label of timetabletag_games
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+	Lobby,
+	InProgress,
+	Finished,
+}
+
+impl Phase {
+	pub fn of(game: &Game) -> Self {
+		return match game {
+			Game::Lobby(_) => Phase::Lobby,
+			Game::InProgress(_) => Phase::InProgress,
+			Game::Finished(_) => Phase::Finished,
+		};
+	}
+}
+
+impl GameCounts {
+	pub fn of_phase(&mut self, phase: Phase) -> &mut usize {
+		return match phase {
+			Phase::Lobby => &mut self.lobby,
+			Phase::InProgress => &mut self.in_progress,
+			Phase::Finished => &mut self.finished,
+		};
+	}
+}
+
+impl Metrics {
+	pub const fn new() -> Self {
+		return Metrics {
+			moves: AtomicU64::new(0),
+			rejected_moves: Mutex::new(BTreeMap::new()),
+			finished_games: Mutex::new(BTreeMap::new()),
+			requests: Mutex::new(BTreeMap::new()),
+			lock_waits: Mutex::new(Histogram::new()),
+		};
+	}
+
+	pub fn record_move(&self) {
+		self.moves.fetch_add(1, Ordering::Relaxed);
+	}
+
+	pub fn record_rejected_move(&self, error_id: &'static str) {
+		*self.rejected_moves.lock().unwrap().entry(error_id).or_default() += 1;
+	}
+
+	pub fn record_finished_game(&self, win_condition: &WinCondition, winning_team: &Team) {
+		let win_condition = match win_condition {
+			WinCondition::RunnerCaught => "runner_caught",
+			WinCondition::GotToDestination => "got_to_destination",
+			WinCondition::TimetableCardsRanOut => "timetable_cards_ran_out",
+		};
+		let winning_team = match winning_team {
+			Team::Runner => "runner",
+			Team::Chaser => "chaser",
+		};
+		*self.finished_games.lock().unwrap().entry((win_condition, winning_team)).or_default() += 1;
+	}
+
+	// route is the pattern the request matched, so every game shares the same series
+	pub fn record_request(&self, method: &str, route: &str, duration: Duration) {
+		self.requests.lock().unwrap().entry((method.to_string(), route.to_string())).or_default().observe(duration);
+	}
+
+	pub fn record_lock_wait(&self, duration: Duration) {
+		self.lock_waits.lock().unwrap().observe(duration);
+	}
+
+	// Prometheus text format, see https://prometheus.io/docs/instrumenting/exposition_formats/
+	pub fn render(&self, games: GameCounts) -> String {
+		let mut out = String::new();
+
+		out.push_str("# HELP timetabletag_games Games the server currently keeps by phase\n");
+		out.push_str("# TYPE timetabletag_games gauge\n");
+		for (phase, count) in [("lobby", games.lobby), ("in_progress", games.in_progress), ("finished", games.finished)] {
+			let _ = writeln!(out, "timetabletag_games{{phase=\"{phase}\"}} {count}");
+		}
+
+		out.push_str("# HELP timetabletag_moves_total Moves that were accepted, including the ones of bots\n");
+		out.push_str("# TYPE timetabletag_moves_total counter\n");
+		let _ = writeln!(out, "timetabletag_moves_total {}", self.moves.load(Ordering::Relaxed));
+
+		out.push_str("# HELP timetabletag_rejected_moves_total Moves that were rejected by error_id\n");
+		out.push_str("# TYPE timetabletag_rejected_moves_total counter\n");
+		for (error_id, count) in self.rejected_moves.lock().unwrap().iter() {
+			let _ = writeln!(out, "timetabletag_rejected_moves_total{{error=\"{error_id}\"}} {count}");
+		}
+
+		out.push_str("# HELP timetabletag_finished_games_total Games that ended by win condition and winning team\n");
+		out.push_str("# TYPE timetabletag_finished_games_total counter\n");
+		for ((win_condition, winning_team), count) in self.finished_games.lock().unwrap().iter() {
+			let _ = writeln!(out, "timetabletag_finished_games_total{{win_condition=\"{win_condition}\",winning_team=\"{winning_team}\"}} {count}");
+		}
+
+		out.push_str("# HELP timetabletag_request_duration_seconds Time it took to answer requests by route\n");
+		out.push_str("# TYPE timetabletag_request_duration_seconds histogram\n");
+		for ((method, route), histogram) in self.requests.lock().unwrap().iter() {
+			histogram.render(&mut out, "timetabletag_request_duration_seconds", &format!("method=\"{method}\",route=\"{route}\","));
+		}
+
+		out.push_str("# HELP timetabletag_lock_wait_seconds Time requests waited for the lock of a game\n");
+		out.push_str("# TYPE timetabletag_lock_wait_seconds histogram\n");
+		self.lock_waits.lock().unwrap().render(&mut out, "timetabletag_lock_wait_seconds", "");
+
+		return out;
+	}
+}
+
+impl Default for Metrics {
+	fn default() -> Self {
+		return Self::new();
+	}
+}
+
+impl Histogram {
+	pub const fn new() -> Self {
+		return Histogram {
+			counts: [0; BUCKETS.len() + 1],
+			sum: 0.0,
+		};
+	}
+
+	pub fn observe(&mut self, duration: Duration) {
+		let seconds = duration.as_secs_f64();
+		let bucket = BUCKETS.iter().position(|x| seconds <= *x).unwrap_or(BUCKETS.len());
+		self.counts[bucket] += 1;
+		self.sum += seconds;
+	}
+
+	// labels is either empty or ends with a comma, so le can be appended
+	fn render(&self, out: &mut String, name: &str, labels: &str) {
+		let mut cumulative = 0;
+		for (i, upper_bound) in BUCKETS.iter().enumerate() {
+			cumulative += self.counts[i];
+			let _ = writeln!(out, "{name}_bucket{{{labels}le=\"{upper_bound}\"}} {cumulative}");
+		}
+		cumulative += self.counts[BUCKETS.len()];
+		let _ = writeln!(out, "{name}_bucket{{{labels}le=\"+Inf\"}} {cumulative}");
+
+		let labels = labels.trim_end_matches(',');
+		let labels = if labels.is_empty() {String::new()} else {format!("{{{labels}}}")};
+		let _ = writeln!(out, "{name}_sum{labels} {}", self.sum);
+		let _ = writeln!(out, "{name}_count{labels} {cumulative}");
+	}
+}
+
+impl Default for Histogram {
+	fn default() -> Self {
+		return Self::new();
+	}
+}
@@ -0,0 +1,123 @@
+use super::*;
+
+fn line<'a>(rendered: &'a str, prefix: &str) -> Option<&'a str> {
+	return rendered.lines().find(|x| x.starts_with(prefix));
+}
+
+mod render {
+	use super::*;
+
+	#[test]
+	fn games_per_phase() {
+		let metrics = Metrics::new();
+
+		let rendered = metrics.render(GameCounts { lobby: 2, in_progress: 5, finished: 1 });
+
+		assert!(rendered.contains("# TYPE timetabletag_games gauge\n"));
+		assert_eq!(line(&rendered, "timetabletag_games{phase=\"lobby\"}"), Some("timetabletag_games{phase=\"lobby\"} 2"));
+		assert_eq!(line(&rendered, "timetabletag_games{phase=\"in_progress\"}"), Some("timetabletag_games{phase=\"in_progress\"} 5"));
+		assert_eq!(line(&rendered, "timetabletag_games{phase=\"finished\"}"), Some("timetabletag_games{phase=\"finished\"} 1"));
+	}
+
+	#[test]
+	fn moves_and_rejected_moves() {
+		let metrics = Metrics::new();
+
+		metrics.record_move();
+		metrics.record_move();
+		metrics.record_rejected_move("not_your_turn");
+		metrics.record_rejected_move("not_your_turn");
+		metrics.record_rejected_move("invalid_next_location");
+		let rendered = metrics.render(GameCounts::default());
+
+		assert_eq!(line(&rendered, "timetabletag_moves_total "), Some("timetabletag_moves_total 2"));
+		assert_eq!(line(&rendered, "timetabletag_rejected_moves_total{error=\"not_your_turn\"}"), Some("timetabletag_rejected_moves_total{error=\"not_your_turn\"} 2"));
+		assert_eq!(line(&rendered, "timetabletag_rejected_moves_total{error=\"invalid_next_location\"}"), Some("timetabletag_rejected_moves_total{error=\"invalid_next_location\"} 1"));
+	}
+
+	#[test]
+	fn finished_games_by_win_condition_and_team() {
+		let metrics = Metrics::new();
+
+		metrics.record_finished_game(&WinCondition::RunnerCaught, &Team::Chaser);
+		metrics.record_finished_game(&WinCondition::GotToDestination, &Team::Runner);
+		metrics.record_finished_game(&WinCondition::RunnerCaught, &Team::Chaser);
+		let rendered = metrics.render(GameCounts::default());
+
+		assert!(rendered.contains("timetabletag_finished_games_total{win_condition=\"runner_caught\",winning_team=\"chaser\"} 2\n"));
+		assert!(rendered.contains("timetabletag_finished_games_total{win_condition=\"got_to_destination\",winning_team=\"runner\"} 1\n"));
+		assert!(!rendered.contains("timetable_cards_ran_out"));
+	}
+
+	#[test]
+	fn request_durations_per_route() {
+		let metrics = Metrics::new();
+
+		metrics.record_request("POST", "/api/v1/games/{game_id}/make_move", Duration::from_millis(3));
+		metrics.record_request("POST", "/api/v1/games/{game_id}/make_move", Duration::from_secs(2));
+		metrics.record_request("GET", "/api/v1/board", Duration::from_micros(100));
+		let rendered = metrics.render(GameCounts::default());
+
+		let labels = "method=\"POST\",route=\"/api/v1/games/{game_id}/make_move\"";
+		assert!(rendered.contains(&format!("timetabletag_request_duration_seconds_bucket{{{labels},le=\"0.0025\"}} 0\n")));
+		assert!(rendered.contains(&format!("timetabletag_request_duration_seconds_bucket{{{labels},le=\"0.005\"}} 1\n")));
+		assert!(rendered.contains(&format!("timetabletag_request_duration_seconds_bucket{{{labels},le=\"1\"}} 1\n")));
+		assert!(rendered.contains(&format!("timetabletag_request_duration_seconds_bucket{{{labels},le=\"+Inf\"}} 2\n")));
+		assert!(rendered.contains(&format!("timetabletag_request_duration_seconds_sum{{{labels}}} 2.003\n")));
+		assert!(rendered.contains(&format!("timetabletag_request_duration_seconds_count{{{labels}}} 2\n")));
+		assert!(rendered.contains("timetabletag_request_duration_seconds_count{method=\"GET\",route=\"/api/v1/board\"} 1\n"));
+	}
+
+	#[test]
+	fn lock_waits_without_labels() {
+		let metrics = Metrics::new();
+
+		let rendered = metrics.render(GameCounts::default());
+		assert!(rendered.contains("timetabletag_lock_wait_seconds_bucket{le=\"+Inf\"} 0\n"));
+		assert!(rendered.contains("timetabletag_lock_wait_seconds_count 0\n"));
+
+		metrics.record_lock_wait(Duration::from_micros(200));
+		let rendered = metrics.render(GameCounts::default());
+		assert!(rendered.contains("timetabletag_lock_wait_seconds_bucket{le=\"0.0005\"} 1\n"));
+		assert!(rendered.contains("timetabletag_lock_wait_seconds_count 1\n"));
+	}
+}
+
+mod game {
+	use super::*;
+	use crate::game::Game;
+	use crate::game::lobby::Lobby;
+	use crate::game::in_progress_game::Move;
+
+	fn rejected(error_id: &str) -> u64 {
+		return METRICS.rejected_moves.lock().unwrap().get(error_id).copied().unwrap_or_default();
+	}
+
+	//the metrics are global and other tests make moves at the same time, so only increases can be checked
+	#[test]
+	fn make_move_counts_rejected_moves() {
+		let mut game = Game::Lobby(Lobby::create("test_1".to_string(), None));
+		let before = rejected("invalid_game_state");
+
+		assert!(game.make_move(Move::default()).is_err());
+
+		assert!(rejected("invalid_game_state") > before);
+	}
+
+	#[test]
+	fn make_move_counts_accepted_moves() {
+		let mut lobby = Lobby::create("test_1".to_string(), Some(5));
+		let _ = lobby.join("test_2".to_string());
+		let _ = lobby.join("test_3".to_string());
+		let mut game = Game::InProgress(lobby.start(lobby.host).unwrap());
+		let move_made = match &game {
+			Game::InProgress(game) => game.next_bot_move(crate::game::bot::BotDifficulty::Easy),
+			_ => unreachable!(),
+		};
+		let before = METRICS.moves.load(Ordering::Relaxed);
+
+		game.make_move(move_made).unwrap();
+
+		assert!(METRICS.moves.load(Ordering::Relaxed) > before);
+	}
+}
@@ -21,30 +21,54 @@ impl Display for Powerup {
 }
 
 impl TryFrom<&str> for Powerup {
-	type Error = String;
-	fn try_from(value: &str) -> Result<Powerup, String> {
+	type Error = crate::CustomError;
+	fn try_from(value: &str) -> Result<Powerup, crate::CustomError> {
 		match value {
 			"learn_runner_country" => Ok(Powerup::LearnRunnerCountry),
 			"learn_runner_location" => Ok(Powerup::LearnRunnerLocation),
 			"chaser_gets_two_turns" => Ok(Powerup::ChaserGetsTwoTurns),
 			"learn_runner_destination" => Ok(Powerup::LearnRunnerDestination),
-			_ => Err(format!("{value} is not a valid powerup")),
+			_ => Err(crate::CustomError::InvalidPowerup(value.to_string())),
 		}
 	}
 }
 
 impl Powerup {
-	pub fn get_price(&self, chaser_count: usize) -> usize {
-		return match self {
-			Powerup::LearnRunnerCountry => if chaser_count == 2 { 5 } else { 10 },
-			Powerup::LearnRunnerLocation => if chaser_count == 2 { 10 } else { 20 },
-			Powerup::ChaserGetsTwoTurns => if chaser_count == 2 { 15 } else { 30 },
-			Powerup::LearnRunnerDestination => if chaser_count == 2 { 20 } else { 40 },
-		}
+	// More chasers collect coins faster, so with more than two of them everything costs twice as much
+	pub fn get_price(&self, chaser_count: usize, prices: &PowerupPrices) -> usize {
+		let price = match self {
+			Powerup::LearnRunnerCountry => prices.learn_runner_country,
+			Powerup::LearnRunnerLocation => prices.learn_runner_location,
+			Powerup::ChaserGetsTwoTurns => prices.chaser_gets_two_turns,
+			Powerup::LearnRunnerDestination => prices.learn_runner_destination,
+		};
+
+		return if chaser_count <= 2 { price } else { price * 2 };
+	}
+}
+
+// What every powerup costs with up to two chasers
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct PowerupPrices {
+	pub learn_runner_country: usize,
+	pub learn_runner_location: usize,
+	pub chaser_gets_two_turns: usize,
+	pub learn_runner_destination: usize,
+}
+
+impl Default for PowerupPrices {
+	fn default() -> Self {
+		return PowerupPrices {
+			learn_runner_country: 5,
+			learn_runner_location: 10,
+			chaser_gets_two_turns: 15,
+			learn_runner_destination: 20,
+		};
 	}
 }
 
-#[derive(Debug, Clone, Default, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub struct PowerupStatus {
 	pub runner_country: Option<Country>,
 	pub runner_location: Option<Location>,
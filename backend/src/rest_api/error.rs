@@ -0,0 +1,75 @@
+use actix_web::{http::StatusCode, HttpRequest, HttpResponse, ResponseError};
+use std::error::Error;
+use crate::CustomError;
+
+// Every error the api returns looks like this, see docs/api-spec.md
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ErrorResponse {
+	pub error_id: String,
+	pub error_message: String,
+}
+
+impl ResponseError for CustomError {
+	fn status_code(&self) -> StatusCode {
+		return match self {
+			CustomError::GameNotFound => StatusCode::NOT_FOUND,
+			CustomError::ChallengeNotFound => StatusCode::NOT_FOUND,
+			CustomError::InviteCodeNotFound => StatusCode::NOT_FOUND,
+			CustomError::MissingSessionToken => StatusCode::UNAUTHORIZED,
+			CustomError::InvalidSessionToken => StatusCode::UNAUTHORIZED,
+			CustomError::ActionNotAllowed => StatusCode::FORBIDDEN,
+			CustomError::UnknownPlayer => StatusCode::FORBIDDEN,
+			CustomError::LobbyFull => StatusCode::CONFLICT,
+			CustomError::DisplayNameTaken => StatusCode::CONFLICT,
+			CustomError::InvalidGameState => StatusCode::CONFLICT,
+			CustomError::ServerBusy => StatusCode::SERVICE_UNAVAILABLE,
+			_ => StatusCode::BAD_REQUEST,
+		};
+	}
+
+	fn error_response(&self) -> HttpResponse {
+		return HttpResponse::build(self.status_code()).json(ErrorResponse::from(self));
+	}
+}
+
+impl From<&CustomError> for ErrorResponse {
+	fn from(value: &CustomError) -> Self {
+		return ErrorResponse {
+			error_id: value.error_id().to_string(),
+			error_message: value.to_string(),
+		};
+	}
+}
+
+impl From<&Box<dyn Error>> for ErrorResponse {
+	fn from(value: &Box<dyn Error>) -> Self {
+		return match value.downcast_ref::<CustomError>() {
+			Some(e) => ErrorResponse::from(e),
+			//anything that isn't a CustomError is a bug on our side
+			None => ErrorResponse {
+				error_id: "internal_error".to_string(),
+				error_message: value.to_string(),
+			},
+		};
+	}
+}
+
+// Turns whatever error the game logic returned into a response
+pub fn error_response(e: Box<dyn Error>) -> HttpResponse {
+	return match e.downcast_ref::<CustomError>() {
+		Some(e) => e.error_response(),
+		None => HttpResponse::InternalServerError().json(ErrorResponse::from(&e)),
+	};
+}
+
+pub fn json_error_handler(e: actix_web::error::JsonPayloadError, _req: &HttpRequest) -> actix_web::Error {
+	return CustomError::InvalidRequestBody(e.to_string()).into();
+}
+
+pub fn query_error_handler(e: actix_web::error::QueryPayloadError, _req: &HttpRequest) -> actix_web::Error {
+	return CustomError::InvalidRequestBody(e.to_string()).into();
+}
+
+pub fn path_error_handler(e: actix_web::error::PathError, _req: &HttpRequest) -> actix_web::Error {
+	return CustomError::InvalidRequestBody(e.to_string()).into();
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod test;
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+use crate::game::GameId;
+
+// Tells everyone listening on a game that it changed.
+// Receivers are expected to fetch the current state themselves, so the same
+// notification can be turned into a different view for every player.
+#[derive(Debug, Default)]
+pub struct GameUpdates {
+	channels: Mutex<BTreeMap<GameId, broadcast::Sender<()>>>,
+}
+
+impl GameUpdates {
+	pub fn new() -> Self {
+		return Self::default();
+	}
+
+	pub fn subscribe(&self, game_id: &GameId) -> broadcast::Receiver<()> {
+		let mut channels = self.channels.lock().unwrap();
+		return channels.entry(*game_id).or_insert_with(|| broadcast::channel(16).0).subscribe();
+	}
+
+	pub fn notify(&self, game_id: &GameId) {
+		let mut channels = self.channels.lock().unwrap();
+		if let Some(sender) = channels.get(game_id) {
+			if sender.send(()).is_err() {
+				//nobody is listening anymore
+				channels.remove(game_id);
+			}
+		}
+	}
+
+	// Wakes up everyone listening on any game, so websockets notice when the server shuts down
+	pub fn notify_all(&self) {
+		for sender in self.channels.lock().unwrap().values() {
+			let _ = sender.send(());
+		}
+	}
+}
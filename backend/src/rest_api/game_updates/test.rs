@@ -0,0 +1,63 @@
+use super::*;
+use uuid::Uuid;
+
+mod notify {
+	use super::*;
+
+	#[test]
+	fn subscribers_get_notified() {
+		let game_updates = GameUpdates::new();
+		let game_id = Uuid::new_v4();
+		let mut receiver_1 = game_updates.subscribe(&game_id);
+		let mut receiver_2 = game_updates.subscribe(&game_id);
+
+		game_updates.notify(&game_id);
+
+		assert!(receiver_1.try_recv().is_ok());
+		assert!(receiver_2.try_recv().is_ok());
+	}
+
+	#[test]
+	fn subscribers_of_other_games_dont_get_notified() {
+		let game_updates = GameUpdates::new();
+		let mut receiver = game_updates.subscribe(&Uuid::new_v4());
+
+		game_updates.notify(&Uuid::new_v4());
+
+		assert!(receiver.try_recv().is_err());
+	}
+
+	#[test]
+	fn notify_without_subscribers_doesnt_panic() {
+		let game_updates = GameUpdates::new();
+		let game_id = Uuid::new_v4();
+		drop(game_updates.subscribe(&game_id));
+
+		game_updates.notify(&game_id);
+		game_updates.notify(&game_id);
+	}
+}
+
+mod notify_all {
+	use super::*;
+
+	#[test]
+	fn subscribers_of_every_game_get_notified() {
+		let game_updates = GameUpdates::new();
+		let mut receiver_1 = game_updates.subscribe(&Uuid::new_v4());
+		let mut receiver_2 = game_updates.subscribe(&Uuid::new_v4());
+
+		game_updates.notify_all();
+
+		assert!(receiver_1.try_recv().is_ok());
+		assert!(receiver_2.try_recv().is_ok());
+	}
+
+	#[test]
+	fn works_without_subscribers() {
+		let game_updates = GameUpdates::new();
+		drop(game_updates.subscribe(&Uuid::new_v4()));
+
+		game_updates.notify_all();
+	}
+}
@@ -1,48 +1,235 @@
+pub mod game_updates;
+pub mod error;
+pub mod session;
+
 use actix_web::{dev::Service as _, web, App, HttpServer, middleware};
-use actix_web::{get, HttpResponse, Responder};
+use actix_web::{get, HttpResponse, Responder, ResponseError};
+use actix_web::dev::ServerHandle;
+use actix_web::http::header::{HeaderName, HeaderValue};
 use futures_util::future::FutureExt;
-use std::{collections::BTreeMap, sync::{Arc, Mutex}};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::game_store::{GameStore, InMemoryGameStore, FileGameStore};
+use crate::config::Config;
+use crate::CustomError;
+use crate::metrics::METRICS;
+use crate::logging;
+use actix_cors::Cors;
+use tracing::Instrument;
 use uuid::Uuid;
 
-use crate::game::Game;
+use game_updates::GameUpdates;
 
 pub struct AppState {
-	pub games: Arc<Mutex<BTreeMap<Uuid, Game>>>,
+	pub games: Box<dyn GameStore>,
+	pub game_updates: GameUpdates,
+	pub config: Config,
+	//set once SIGTERM arrived, see shutdown_on_signal
+	pub shutting_down: AtomicBool,
 	pub test: Arc<Mutex<usize>>,
 }
 
 pub async fn initialize_webserver() -> std::io::Result<()> {
-	let api_port = 4000;
-	println!("Starting webserver on port {}", api_port);
+	let config = match Config::load() {
+		Ok(config) => config,
+		Err(e) => return Err(std::io::Error::other(format!("invalid configuration: {e}"))),
+	};
+	logging::init(&config);
+	tracing::info!("Starting webserver on {}:{}", config.bind_address, config.port);
+
+	if let Some(path) = &config.board_path {
+		tracing::info!("Loading board from {}", path.display());
+		if let Err(e) = crate::location::board::load_board(path) {
+			return Err(std::io::Error::other(format!("failed to load board from {}: {e}", path.display())));
+		}
+	}
+
+	let game_store: Box<dyn GameStore> = match &config.persistence_path {
+		Some(path) => {
+			tracing::info!("Persisting games to {}", path.display());
+			match FileGameStore::open(path) {
+				Ok(store) => Box::new(store),
+				Err(e) => return Err(std::io::Error::other(format!("failed to load games from {}: {e}", path.display()))),
+			}
+		},
+		None => Box::new(InMemoryGameStore::new()),
+	};
 
 	let state = web::Data::new(AppState {
-		games: Arc::new(Mutex::new(BTreeMap::new())),
+		games: game_store,
+		game_updates: GameUpdates::new(),
+		config: config.clone(),
+		shutting_down: AtomicBool::new(false),
 		test: Arc::new(Mutex::new(0)),
 	});
 
-	return HttpServer::new(move|| {
+	actix_web::rt::spawn(crate::game::turn_timer::run(state.clone()));
+	actix_web::rt::spawn(crate::game_store::sweeper::run(state.clone(), config.expiry.clone()));
+
+	//the app factory below takes state
+	let shutdown_state = state.clone();
+	let mut server = HttpServer::new(move|| {
 		return App::new()
 			.app_data(state.clone())
+			.app_data(web::JsonConfig::default().limit(state.config.limits.max_body_size).error_handler(error::json_error_handler))
+			.app_data(web::QueryConfig::default().error_handler(error::query_error_handler))
+			.app_data(web::PathConfig::default().error_handler(error::path_error_handler))
+			.wrap(middleware::from_fn(session::resolve_session))
 			.wrap(middleware::Compress::default())
 			.wrap(middleware::DefaultHeaders::new().add(("Content-Type", "application/json")))
+			.wrap(middleware::Condition::new(!state.config.cors_origins.is_empty(), cors(&state.config.cors_origins)))
 			.wrap_fn(|req, srv| {
-				println!("req: {} {}", req.method(), req.path());
-				srv.call(req).map(|res| {
+				let started_at = std::time::Instant::now();
+				let method = req.method().to_string();
+				//the pattern instead of the path, otherwise every game would get its own series
+				let route = req.match_pattern().unwrap_or_else(|| "unmatched".to_string());
+				//sent back in the x-request-id header, so a failed request can be found in the logs
+				let request_id = Uuid::new_v4().to_string();
+				let span = logging::request_span(&request_id, &method, &route);
+				srv.call(req).map(move |mut res| {
+					let duration = started_at.elapsed();
+					METRICS.record_request(&method, &route, duration);
+					let status = match &mut res {
+						Ok(res) => {
+							res.headers_mut().insert(HeaderName::from_static("x-request-id"), HeaderValue::from_str(&request_id).unwrap());
+							res.status()
+						},
+						Err(e) => e.as_response_error().status_code(),
+					};
+					tracing::debug!(status = status.as_u16(), duration_ms = duration.as_millis() as u64, "request finished");
 					return res;
-				})
+				}).instrument(span)
 			})
 			.service(test)
+			.service(healthz)
+			.service(readyz)
+			.service(metrics)
 			.service(crate::game::rest_api::join_game)
+			.service(crate::game::rest_api::join_as_spectator)
+			.service(crate::game::rest_api::add_bot)
+			.service(crate::game::rest_api::set_spectator_settings)
+			.service(crate::game::rest_api::set_settings)
+			.service(crate::game::rest_api::leave_game)
+			.service(crate::game::rest_api::vote_on_challenge)
+			.service(crate::game::rest_api::kick_player)
+			.service(crate::game::rest_api::transfer_host)
+			.service(crate::game::rest_api::set_ready)
+			.service(crate::game::rest_api::set_require_ready)
 			.service(crate::game::rest_api::start_game)
 			.service(crate::game::rest_api::make_move)
+			.service(crate::game::rest_api::take_back)
+			.service(crate::game::rest_api::rematch)
+			.service(crate::game::rest_api::next_round)
 			.service(crate::game::rest_api::get_current_state)
-			.service(crate::game::rest_api::create_game);
-	})
-		.bind(("0.0.0.0", api_port))?
-		.run()
-		.await;
+			.service(crate::game::rest_api::get_history)
+			.service(crate::game::rest_api::get_legal_moves)
+			.service(crate::game::rest_api::create_game)
+			.service(crate::game::websocket::connect)
+			.service(crate::location::rest_api::get_board);
+	});
+
+	if let Some(workers) = config.workers {
+		server = server.workers(workers);
+	}
+
+	//actix would stop on its own, but new games have to be refused and websockets closed first
+	let server = server
+		.disable_signals()
+		.shutdown_timeout(config.shutdown_timeout)
+		.bind((config.bind_address, config.port))?
+		.run();
+	actix_web::rt::spawn(shutdown_on_signal(shutdown_state.clone(), server.handle()));
+	server.await?;
+
+	flush_games(shutdown_state).await;
+	return Ok(());
+}
+
+// Once SIGTERM or ctrl+c arrive readiness fails, new games are refused and websockets get closed.
+// Requests that are still running, like make_move, get shutdown_timeout seconds to finish
+async fn shutdown_on_signal(data: web::Data<AppState>, server: ServerHandle) {
+	wait_for_signal().await;
+	tracing::info!("shutting down");
+
+	data.shutting_down.store(true, Ordering::SeqCst);
+	data.game_updates.notify_all();
+	server.stop(true).await;
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() {
+	use actix_web::rt::signal::unix::{signal, SignalKind};
+
+	let mut sigterm = match signal(SignalKind::terminate()) {
+		Ok(sigterm) => sigterm,
+		Err(e) => {
+			tracing::error!(error = %e, "couldn't listen for SIGTERM");
+			let _ = actix_web::rt::signal::ctrl_c().await;
+			return;
+		},
+	};
+
+	tokio::select! {
+		_ = sigterm.recv() => {},
+		_ = actix_web::rt::signal::ctrl_c() => {},
+	}
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() {
+	let _ = actix_web::rt::signal::ctrl_c().await;
+}
+
+// Waits until nothing holds a game anymore and writes them to disk one last time.
+// The locks are kept until the end, so no game can change while it's flushed
+async fn flush_games(data: web::Data<AppState>) {
+	let mut games = Vec::new();
+	for game_id in data.games.game_ids() {
+		if let Some(game) = data.games.get(&game_id) {
+			games.push(game.lock_owned().await);
+		}
+	}
+
+	let store = data.clone();
+	match web::block(move || store.games.flush().map_err(|e| e.to_string())).await {
+		Ok(Ok(_)) => tracing::info!(games = games.len(), "flushed games"),
+		Ok(Err(e)) => tracing::error!(error = %e, "couldn't flush games"),
+		Err(e) => tracing::error!(error = %e, "couldn't flush games"),
+	}
 }
 
+// Browsers may only call the api from the configured origins
+fn cors(origins: &[String]) -> Cors {
+	let mut cors = Cors::default().allow_any_method().allow_any_header().max_age(3600);
+	for origin in origins {
+		cors = if origin == "*" {cors.allow_any_origin()} else {cors.allowed_origin(origin)};
+	}
+
+	return cors;
+}
+
+// The process is up and answers requests
+#[get("/healthz")]
+pub async fn healthz() -> impl Responder {
+	return HttpResponse::Ok().body("{\"status\":\"ok\"}");
+}
+
+// Whether the server takes new requests, fails with server_busy once it's shutting down
+#[get("/readyz")]
+pub async fn readyz(data: web::Data<AppState>) -> impl Responder {
+	if data.shutting_down.load(Ordering::SeqCst) {
+		return CustomError::ServerBusy.error_response();
+	}
+
+	return HttpResponse::Ok().body("{\"status\":\"ready\"}");
+}
+
+// Prometheus scrapes this, see metrics.rs
+#[get("/metrics")]
+pub async fn metrics(data: web::Data<AppState>) -> impl Responder {
+	return HttpResponse::Ok().content_type("text/plain; version=0.0.4").body(METRICS.render(data.games.game_counts()));
+}
 
 #[get("/api/v1/test")]
 pub async fn test(data: web::Data<AppState>) -> impl Responder {
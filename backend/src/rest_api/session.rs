@@ -0,0 +1,74 @@
+use actix_web::{body::MessageBody, dev::{Payload, ServiceRequest, ServiceResponse}, middleware::Next, web, FromRequest, HttpMessage, HttpRequest};
+use std::future::{ready, Ready};
+use crate::game::{GameId, PlayerId};
+use crate::game::spectator::Participant;
+use crate::CustomError;
+use super::AppState;
+
+// Browsers can't set headers on websockets, so they send the token as the second subprotocol: ["bearer", token]
+pub const WEBSOCKET_PROTOCOL: &str = "bearer";
+
+// The game and player or spectator a session token belongs to, put into the request by resolve_session
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Session {
+	pub game_id: GameId,
+	pub participant: Participant,
+}
+
+impl Session {
+	// Sessions only work for the game they were issued for
+	pub fn participant_in(&self, game_id: &GameId) -> Result<Participant, CustomError> {
+		if self.game_id != *game_id {
+			return Err(CustomError::UnknownPlayer);
+		}
+
+		return Ok(self.participant);
+	}
+
+	// For everything spectators aren't allowed to do
+	pub fn player_id_in(&self, game_id: &GameId) -> Result<PlayerId, CustomError> {
+		return match self.participant_in(game_id)? {
+			Participant::Player(player_id) => Ok(player_id),
+			Participant::Spectator(_) => Err(CustomError::ActionNotAllowed),
+		};
+	}
+}
+
+impl FromRequest for Session {
+	type Error = CustomError;
+	type Future = Ready<Result<Session, CustomError>>;
+
+	fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+		return ready(req.extensions().get::<Session>().copied().ok_or(CustomError::MissingSessionToken));
+	}
+}
+
+// Looks up the session token of every request that sends one, requests without a token go through untouched
+// and only fail in handlers that need a Session
+pub async fn resolve_session(req: ServiceRequest, next: Next<impl MessageBody>) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+	if let Some(session_token) = session_token(req.request()) {
+		let data = req.app_data::<web::Data<AppState>>().unwrap();
+
+		match data.games.find_session(&session_token) {
+			Some((game_id, participant)) => {
+				crate::logging::record_session(&game_id, &participant);
+				req.extensions_mut().insert(Session { game_id, participant });
+			},
+			None => return Err(CustomError::InvalidSessionToken.into()),
+		}
+	}
+
+	return next.call(req).await;
+}
+
+pub fn session_token(req: &HttpRequest) -> Option<String> {
+	if let Some(authorization) = req.headers().get("Authorization").and_then(|x| x.to_str().ok()) {
+		return authorization.strip_prefix("Bearer ").map(|x| x.trim().to_string());
+	}
+
+	let protocols: Vec<&str> = req.headers().get("Sec-WebSocket-Protocol").and_then(|x| x.to_str().ok())?.split(',').map(|x| x.trim()).collect();
+	return match protocols.as_slice() {
+		[WEBSOCKET_PROTOCOL, session_token] => Some(session_token.to_string()),
+		_ => None,
+	};
+}
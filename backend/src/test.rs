@@ -0,0 +1,106 @@
+use super::*;
+use std::collections::BTreeSet;
+use actix_web::{http::StatusCode, ResponseError};
+
+fn all_errors() -> Vec<CustomError> {
+	return vec![
+		CustomError::LobbyFull,
+		CustomError::DisplayNameTaken,
+		CustomError::LobbyNotFullEnough,
+		CustomError::NotEveryoneReady,
+		CustomError::InvalidGameState,
+		CustomError::ActionNotAllowed,
+		CustomError::NotYourTurn,
+		CustomError::InvalidNextLocation,
+		CustomError::MissingTimetableCard,
+		CustomError::AlreadyMoved,
+		CustomError::NotEnoughCoins,
+		CustomError::EventCardNoLocationSent,
+		CustomError::EventCardAlreadyBought,
+		CustomError::NotAnEventField,
+		CustomError::EventCardStackEmpty,
+		CustomError::EventCardNotOnYourHand,
+		CustomError::YoureCurrentlyHuntedByMenForSport,
+		CustomError::YouMustGoToGermanyOrFrance,
+		CustomError::YouMustGoNorth,
+		CustomError::YouAreCurrentlyInRatMode,
+		CustomError::YouMustLeaveTheCountryImmediately,
+		CustomError::ValidMovePossible,
+		CustomError::ThrewTooManyTimetableCardsAway,
+		CustomError::GameNotFound,
+		CustomError::ChallengeNotFound,
+		CustomError::InviteCodeNotFound,
+		CustomError::MissingSessionToken,
+		CustomError::InvalidSessionToken,
+		CustomError::UnknownPlayer,
+		CustomError::ServerBusy,
+		CustomError::InvalidRequestBody("test".to_string()),
+		CustomError::InvalidLocation("test".to_string()),
+		CustomError::InvalidTimetableCard("test".to_string()),
+		CustomError::InvalidEventCard("test".to_string()),
+		CustomError::InvalidPowerup("test".to_string()),
+		CustomError::InvalidSettings("test".to_string()),
+	];
+}
+
+mod error_id {
+	use super::*;
+
+	#[test]
+	fn error_ids_are_unique() {
+		let error_ids: BTreeSet<&str> = all_errors().iter().map(|x| x.error_id()).collect();
+
+		assert_eq!(error_ids.len(), all_errors().len());
+	}
+
+	#[test]
+	fn error_ids_are_snake_case() {
+		for error in all_errors() {
+			println!("checking {error:?}");
+			assert!(error.error_id().chars().all(|x| x.is_ascii_lowercase() || x == '_'));
+		}
+	}
+
+	#[test]
+	fn parse_errors_have_their_own_error_id() {
+		assert_eq!(location::Location::try_from("atlantis").err().unwrap().error_id(), "invalid_location");
+		assert_eq!(timetable_card::TimetableCard::try_from("rocket").err().unwrap().error_id(), "invalid_timetable_card");
+		assert_eq!(event_card::EventCard::try_from("not_a_card".to_string()).err().unwrap().error_id(), "invalid_event_card");
+		assert_eq!(powerup::Powerup::try_from("teleport").err().unwrap().error_id(), "invalid_powerup");
+	}
+}
+
+mod status_code {
+	use super::*;
+
+	#[test]
+	fn no_error_is_a_server_error_except_busy() {
+		for error in all_errors() {
+			println!("checking {error:?}");
+			if matches!(error, CustomError::ServerBusy) {
+				assert!(error.status_code().is_server_error());
+			} else {
+				assert!(error.status_code().is_client_error());
+			}
+		}
+	}
+
+	#[test]
+	fn illegal_moves_are_bad_requests() {
+		assert_eq!(CustomError::NotEnoughCoins.status_code(), StatusCode::BAD_REQUEST);
+		assert_eq!(CustomError::InvalidNextLocation.status_code(), StatusCode::BAD_REQUEST);
+		assert_eq!(CustomError::InvalidLocation("test".to_string()).status_code(), StatusCode::BAD_REQUEST);
+	}
+
+	#[test]
+	fn missing_things_are_not_found() {
+		assert_eq!(CustomError::GameNotFound.status_code(), StatusCode::NOT_FOUND);
+		assert_eq!(CustomError::InviteCodeNotFound.status_code(), StatusCode::NOT_FOUND);
+	}
+
+	#[test]
+	fn session_problems_are_unauthorized() {
+		assert_eq!(CustomError::MissingSessionToken.status_code(), StatusCode::UNAUTHORIZED);
+		assert_eq!(CustomError::InvalidSessionToken.status_code(), StatusCode::UNAUTHORIZED);
+	}
+}
\ No newline at end of file
@@ -24,23 +24,23 @@ impl Display for TimetableCard {
 }
 
 impl std::convert::TryFrom<String> for TimetableCard {
-	type Error = String;
+	type Error = crate::CustomError;
 
-	fn try_from(value: String) -> Result<TimetableCard, String> {
+	fn try_from(value: String) -> Result<TimetableCard, crate::CustomError> {
 		return value.as_str().try_into();
 	}
 }
 
 impl std::convert::TryFrom<&str> for TimetableCard {
-	type Error = String;
+	type Error = crate::CustomError;
 	
-	fn try_from(value: &str) -> Result<TimetableCard, String> {
+	fn try_from(value: &str) -> Result<TimetableCard, crate::CustomError> {
 		match value {
 			"low_speed" => Ok(TimetableCard::LowSpeed),
 			"high_speed" => Ok(TimetableCard::HighSpeed),
 			"plane" => Ok(TimetableCard::Plane),
 			"joker" => Ok(TimetableCard::Joker),
-			_ => Err(format!("{value} not a valid TimetableCard ID")),
+			_ => Err(crate::CustomError::InvalidTimetableCard(value.to_string())),
 		}
 	}
 }
@@ -51,8 +51,7 @@ impl std::convert::TryFrom<&str> for TimetableCard {
 // plane:      16 = 16%
 // joker:       4 =  4%
 // total:     100 =100%
-pub fn generate_timetable_card_stack() -> Vec<TimetableCard> {
-	let mut rng = thread_rng();
+pub fn generate_timetable_card_stack(rng: &mut impl Rng) -> Vec<TimetableCard> {
 	let mut output: Vec<TimetableCard> = Vec::new();
 	
 	for _ in 0..50 {
@@ -68,7 +67,7 @@ pub fn generate_timetable_card_stack() -> Vec<TimetableCard> {
 		output.push(TimetableCard::Joker);
 	}
 
-	output.shuffle(&mut rng);
+	output.shuffle(rng);
 
 	return output;
 }
\ No newline at end of file
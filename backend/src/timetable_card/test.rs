@@ -11,7 +11,7 @@ mod generate_timetable_card_stack {
 		//check the first
 		let mut output: BTreeMap<TimetableCard, usize> = BTreeMap::new();
 		for _ in 0..n {
-			output.entry(generate_timetable_card_stack().first().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
+			output.entry(generate_timetable_card_stack(&mut thread_rng()).first().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
 		}
 
 		assert!(*output.get(&TimetableCard::LowSpeed).unwrap() as f64 / n as f64 > 0.4);
@@ -29,7 +29,7 @@ mod generate_timetable_card_stack {
 		//and again the last
 		let mut output: BTreeMap<TimetableCard, usize> = BTreeMap::new();
 		for _ in 0..n {
-			output.entry(generate_timetable_card_stack().pop().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
+			output.entry(generate_timetable_card_stack(&mut thread_rng()).pop().unwrap().clone()).and_modify(|x| *x += 1).or_insert(1);
 		}
 
 		assert!(*output.get(&TimetableCard::LowSpeed).unwrap() as f64 / n as f64 > 0.4);
@@ -49,7 +49,7 @@ mod generate_timetable_card_stack {
 
 	#[test]
 	fn card_stack_contains_right_amount_of_each() {
-		let res = generate_timetable_card_stack();
+		let res = generate_timetable_card_stack(&mut thread_rng());
 		
 		assert_eq!(res.len(), 100);
 		assert_eq!(res.iter().filter(|x| **x == TimetableCard::LowSpeed).count(), 50);
@@ -57,4 +57,12 @@ mod generate_timetable_card_stack {
 		assert_eq!(res.iter().filter(|x| **x == TimetableCard::Plane).count(), 16);
 		assert_eq!(res.iter().filter(|x| **x == TimetableCard::Joker).count(), 4);
 	}
+
+	#[test]
+	fn same_seed_results_in_same_order() {
+		let res_1 = generate_timetable_card_stack(&mut rand_chacha::ChaCha8Rng::seed_from_u64(1234));
+		let res_2 = generate_timetable_card_stack(&mut rand_chacha::ChaCha8Rng::seed_from_u64(1234));
+
+		assert_eq!(res_1, res_2);
+	}
 }
\ No newline at end of file
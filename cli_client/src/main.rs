@@ -15,6 +15,7 @@ fn main() {
 
   let game_id: String;
   let player_id: String;
+  let session_token: String;
 
   let create_or_join = input("(c)reate or (j)oin game?: ");
   let display_name = input("Your Name: ");
@@ -33,6 +34,7 @@ fn main() {
 
     game_id = create_game_res.game_id;
     player_id = create_game_res.player_id;
+    session_token = create_game_res.session_token;
 
     let mut start_game = false;
     while !start_game {
@@ -41,7 +43,8 @@ fn main() {
     }
 
     let _ = ureq::post(format!("http://localhost:4000/api/v1/games/{}/start", &game_id))
-      .send_json(&StartGamePostBody {player_id: player_id.clone()})
+      .header("Authorization", format!("Bearer {session_token}"))
+      .send_empty()
       .unwrap()
       .body_mut()
       .read_to_string()
@@ -60,6 +63,7 @@ fn main() {
 
     game_id = join_game_res.game_id;
     player_id = join_game_res.player_id;
+    session_token = join_game_res.session_token;
 
     let _ = input("(c)ontinue: ");
   }
@@ -67,7 +71,7 @@ fn main() {
   let mut last_game_state = InProgressGameState::default();
 
   loop {
-    let current_state = get_in_progress_game_state(&game_id, &player_id);
+    let current_state = get_in_progress_game_state(&game_id, &session_token);
     if current_state.is_err() {
       println!("got error while trying to get in_progress_game_state: {}", current_state.err().unwrap());
       std::thread::sleep(std::time::Duration::from_secs(1));
@@ -81,9 +85,9 @@ fn main() {
     }
     
     last_game_state = current_state.clone();
-    if current_state.current_turn == display_name {
+    if current_state.current_turn == player_id {
       println!("its your turn!");
-      let res = make_move(&game_id, &player_id);
+      let res = make_move(&game_id, &session_token);
       println!("{res:?}");
       println!("{res}");
     }
@@ -111,9 +115,9 @@ fn get_lobby_game_state(game_id: &str) -> LobbyGameState {
     .unwrap();
 }
 
-fn get_in_progress_game_state(game_id: &str, player_id: &str) -> Result<InProgressGameState, String> {
+fn get_in_progress_game_state(game_id: &str, session_token: &str) -> Result<InProgressGameState, String> {
   return match ureq::get(format!("http://localhost:4000/api/v1/games/{game_id}/current_state"))
-    .query("player_id", player_id)
+    .header("Authorization", format!("Bearer {session_token}"))
     .call() {
       Ok(mut x) => {
         Ok(x.body_mut()
@@ -127,13 +131,12 @@ fn get_in_progress_game_state(game_id: &str, player_id: &str) -> Result<InProgre
     }
 }
 
-fn make_move(game_id: &str, player_id: &str) -> String {
+fn make_move(game_id: &str, session_token: &str) -> String {
   let type_of_move = input("(b)uy/(u)se event card, buy (p)owerup, (t)hrow timetable cards away, (m)ove or (f)inish move? ");
 
   let move_to_make = match type_of_move.as_str() {
     "b" => {
       MakeMovePostBody {
-        player_id: player_id.to_string(),
         buy_event_card: true,
         ..Default::default()
       }
@@ -141,7 +144,6 @@ fn make_move(game_id: &str, player_id: &str) -> String {
     "u" => {
       let event_card_id = input("event card to play: ");
       MakeMovePostBody {
-        player_id: player_id.to_string(),
         use_event_card: Some(event_card_id),
         ..Default::default()
       }
@@ -149,7 +151,6 @@ fn make_move(game_id: &str, player_id: &str) -> String {
     "p" => {
       let powerup_to_buy = input("powerup to buy: ");
       MakeMovePostBody {
-        player_id: player_id.to_string(),
         buy_powerup: Some(powerup_to_buy),
         ..Default::default()
       }
@@ -177,7 +178,6 @@ fn make_move(game_id: &str, player_id: &str) -> String {
         timetable_cards.push(timetable_card_2.to_string());
       }
       MakeMovePostBody {
-        player_id: player_id.to_string(),
         throw_timetable_cards_away: timetable_cards,
         ..Default::default()
       }
@@ -193,7 +193,6 @@ fn make_move(game_id: &str, player_id: &str) -> String {
       let next_location = input("next location: ");
     
       MakeMovePostBody {
-        player_id: player_id.to_string(),
         next_location: Some(next_location),
         use_timetable_card: Some(timetable_card.to_string()),
         ..Default::default()
@@ -201,7 +200,6 @@ fn make_move(game_id: &str, player_id: &str) -> String {
     },
     "f" => {
       MakeMovePostBody {
-        player_id: player_id.to_string(),
         finish_move: true,
         ..Default::default()
       }
@@ -215,6 +213,7 @@ fn make_move(game_id: &str, player_id: &str) -> String {
     .http_status_as_error(false)
     .build())
     .post(format!("http://localhost:4000/api/v1/games/{game_id}/make_move"))
+    .header("Authorization", format!("Bearer {session_token}"))
     .send_json(&move_to_make)
     .unwrap()
     .body_mut()
@@ -238,6 +237,7 @@ struct CreateGamePostResponse {
   game_id: String,
   invite_code: String,
   player_id: String,
+  session_token: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -249,20 +249,23 @@ struct JoinGamePostBody {
 struct JoinGamePostResponse {
   game_id: String,
   player_id: String,
+  session_token: String,
 }
 
 #[derive(Debug, Deserialize)]
 struct LobbyGameState {
-  players: Vec<String>,
+  players: Vec<PlayerView>,
 }
 
-#[derive(Debug, Serialize)]
-struct StartGamePostBody {
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+struct PlayerView {
   player_id: String,
+  display_name: String,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Deserialize)]
 struct InProgressGameState {
+  players: Vec<PlayerView>,
   runner: String,
 	destination: Option<String>,
 	current_turn: String,
@@ -282,13 +285,18 @@ struct InProgressGameState {
 
 impl Display for InProgressGameState {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    return write!(f, "The runner is: {}\nCurrent turn: {}\nYour timetable cards: {}\nYour location: {}", self.runner, self.current_turn, self.your_timetable_cards.join(", "), self.your_current_location);
+    return write!(f, "The runner is: {}\nCurrent turn: {}\nYour timetable cards: {}\nYour location: {}", self.display_name(&self.runner), self.display_name(&self.current_turn), self.your_timetable_cards.join(", "), self.your_current_location);
+  }
+}
+
+impl InProgressGameState {
+  fn display_name(&self, player_id: &str) -> String {
+    return self.players.iter().find(|x| x.player_id == player_id).map(|x| x.display_name.clone()).unwrap_or_default();
   }
 }
 
 #[derive(Debug, Default, Serialize)]
 struct MakeMovePostBody {
-  player_id: String,
 	next_location: Option<String>,
 	use_timetable_card: Option<String>,
 	buy_event_card: bool,